@@ -75,9 +75,46 @@ test("exports are available", () => {
 	assert.equal(typeof abruptCrypto.md5, "function")
 	assert.equal(typeof abruptCrypto.sha256, "function")
 	assert.equal(typeof abruptCrypto.sha512, "function")
+	assert.equal(typeof abruptCrypto.sha1, "function")
+	assert.equal(typeof abruptCrypto.sha3_256, "function")
+	assert.equal(typeof abruptCrypto.keccak256, "function")
+	assert.equal(typeof abruptCrypto.shake128, "function")
+	assert.equal(typeof abruptCrypto.blake2b, "function")
+	assert.equal(typeof abruptCrypto.blake3, "function")
+	assert.equal(typeof abruptCrypto.hmac, "function")
+	assert.equal(typeof abruptCrypto.hmacVerify, "function")
+	assert.equal(typeof abruptCrypto.Hmac, "function")
+	assert.equal(typeof abruptCrypto.hkdf, "function")
+	assert.equal(typeof abruptCrypto.pbkdf2, "function")
+	assert.equal(typeof abruptCrypto.timingSafeEqual, "function")
+	assert.equal(typeof abruptCrypto.verifyDigest, "function")
+	assert.equal(typeof abruptCrypto.xxh3, "function")
+	assert.equal(typeof abruptCrypto.crc32, "function")
 	assert.equal(typeof abruptCrypto.rot, "function")
 	assert.equal(typeof base64.encode, "function")
 	assert.equal(typeof base64.decode, "function")
+	assert.equal(typeof base64.decodeBytes, "function")
+	assert.equal(typeof abruptCrypto.base16.decodeBytes, "function")
+	assert.equal(typeof abruptCrypto.base32.decodeBytes, "function")
+	assert.equal(typeof abruptCrypto.base58.encode, "function")
+	assert.equal(typeof abruptCrypto.base58check.decodeBytes, "function")
+	assert.equal(typeof abruptCrypto.base85.encode, "function")
+	assert.equal(typeof abruptCrypto.base85.decodeBytes, "function")
+	assert.equal(typeof abruptCrypto.baseN.encode, "function")
+	assert.equal(typeof abruptCrypto.baseN.decodeBigInt, "function")
+	assert.equal(typeof abruptCrypto.bech32.encode, "function")
+	assert.equal(typeof abruptCrypto.percent.encode, "function")
+	assert.equal(typeof abruptCrypto.html.decode, "function")
+	assert.equal(typeof abruptCrypto.quotedPrintable.decodeBytes, "function")
+	assert.equal(typeof abruptCrypto.encodedWord.encode, "function")
+	assert.equal(typeof abruptCrypto.bech32m.decode, "function")
+	assert.equal(typeof abruptCrypto.detect, "function")
+	assert.equal(typeof abruptCrypto.classic.vigenere.encrypt, "function")
+	assert.equal(typeof abruptCrypto.classic.bacon.decrypt, "function")
+	assert.equal(typeof abruptCrypto.crack.rot, "function")
+	assert.equal(typeof abruptCrypto.crack.vigenere, "function")
+	assert.equal(typeof abruptCrypto.analysis.frequency, "function")
+	assert.equal(typeof abruptCrypto.unwrap, "function")
 	assert.equal(typeof abruptCrypto.base16.encode, "function")
 	assert.equal(typeof abruptCrypto.base16.decode, "function")
 	assert.equal(typeof abruptCrypto.base32.encode, "function")
@@ -86,8 +123,26 @@ test("exports are available", () => {
 	assert.equal(typeof abruptCrypto.AES.decode, "function")
 	assert.equal(typeof abruptCrypto.RSA.encode, "function")
 	assert.equal(typeof abruptCrypto.RSA.decode, "function")
+	assert.equal(typeof abruptCrypto.RSA.encodeAsync, "function")
+	assert.equal(typeof abruptCrypto.RSA.decodeAsync, "function")
+	assert.equal(typeof abruptCrypto.AES.encodeAsync, "function")
+	assert.equal(typeof abruptCrypto.AES.decodeAsync, "function")
+	assert.equal(typeof abruptCrypto.sha256Async, "function")
+	assert.equal(typeof abruptCrypto.blake3Async, "function")
+	assert.equal(typeof abruptCrypto.pbkdf2Async, "function")
 	assert.equal(typeof abruptCrypto.morse.encode, "function")
 	assert.equal(typeof abruptCrypto.morse.decode, "function")
+	assert.equal(typeof abruptCrypto.morse.toAudio, "function")
+	assert.equal(typeof abruptCrypto.morse.decodeTimings, "function")
+	assert.equal(typeof abruptCrypto.morse.decodeAudio, "function")
+	assert.equal(typeof abruptCrypto.password.hash, "function")
+	assert.equal(typeof abruptCrypto.password.verify, "function")
+	assert.equal(typeof abruptCrypto.password.needsRehash, "function")
+	assert.equal(typeof abruptCrypto.merkle.root, "function")
+	assert.equal(typeof abruptCrypto.merkle.proof, "function")
+	assert.equal(typeof abruptCrypto.merkle.verify, "function")
+	assert.equal(typeof abruptCrypto.manifest.create, "function")
+	assert.equal(typeof abruptCrypto.manifest.verify, "function")
 
 	assert.equal(typeof file.create, "function")
 	assert.equal(typeof file.exists, "function")
@@ -194,6 +249,950 @@ test("crypto namespace behaviors", () => {
 	assert.equal(morse.decode(morseEncoded), "hello world!")
 })
 
+test("crypto extended hash family", () => {
+	const input = "Hello World!"
+	const nodeHashes = [
+		["sha1", "sha1"],
+		["sha224", "sha224"],
+		["sha384", "sha384"],
+		["sha512_256", "sha512-256"],
+		["sha3_256", "sha3-256"],
+		["sha3_512", "sha3-512"],
+	]
+	for (const [name, algorithm] of nodeHashes) {
+		const expectedHex = crypto.createHash(algorithm).update(input).digest("hex")
+		const expectedBase64 = crypto
+			.createHash(algorithm)
+			.update(input)
+			.digest("base64")
+		assert.equal(abruptCrypto[name](input), expectedHex)
+		assert.equal(abruptCrypto[name](input, "base64"), expectedBase64)
+	}
+
+	assert.equal(
+		abruptCrypto.keccak256(""),
+		"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+	)
+
+	const shake128 = crypto
+		.createHash("shake128", { outputLength: 32 })
+		.update(input)
+		.digest("hex")
+	const shake256 = crypto
+		.createHash("shake256", { outputLength: 100 })
+		.update(input)
+		.digest("hex")
+	assert.equal(abruptCrypto.shake128(input), shake128)
+	assert.equal(abruptCrypto.shake256(input, 100), shake256)
+	assert.throws(() => abruptCrypto.shake128(input, 0))
+	assert.equal(abruptCrypto.shake256(input, 1024 * 1024).length, 2 * 1024 * 1024)
+	assert.throws(() => abruptCrypto.shake256(input, 1024 * 1024 + 1), /1\.\.=1048576/)
+})
+
+test("crypto binary encodings", () => {
+	const input = "Hello World!"
+	const digest = crypto.createHash("sha256").update(input).digest()
+	assert.equal(abruptCrypto.sha256(input, "base64url"), digest.toString("base64url"))
+	assert.equal(
+		abruptCrypto.sha256(input, "base64-nopad"),
+		digest.toString("base64").replace(/=+$/, ""),
+	)
+	assert.match(abruptCrypto.sha256(input, "base32"), /^[A-Z2-7]{52}=*$/)
+	assert.deepEqual(abruptCrypto.sha256(input, "buffer"), digest)
+	assert.deepEqual(abruptCrypto.blake3(input, { encoding: "buffer" }).length, 32)
+	assert.equal(Buffer.isBuffer(abruptCrypto.hmac("sha1", "k", input, "buffer")), true)
+	assert.throws(() => abruptCrypto.sha256(input, "base58"))
+
+	for (const encoding of ["base64url", "base64-nopad", "base32"]) {
+		const encoded = abruptCrypto.sha256(input, encoding)
+		assert.equal(abruptCrypto.verifyDigest(input, encoded, "sha256", encoding), true)
+	}
+	assert.equal(abruptCrypto.verifyDigest(input, abruptCrypto.sha256(input, "base64url")), true)
+	assert.equal(abruptCrypto.verifyDigest(input, abruptCrypto.sha256(input, "buffer")), true)
+
+	const { AES, RSA } = abruptCrypto
+	for (const encoding of ["base64url", "base64-nopad", "base32", "buffer"]) {
+		const packet = AES.encode(input, "key", encoding)
+		assert.equal(AES.decode(...packet, "key", encoding), input)
+	}
+	const [rawAes, ivHex] = AES.encode(input, "key", "buffer")
+	assert.equal(Buffer.isBuffer(rawAes), true)
+	assert.equal(AES.decode(rawAes, ivHex, "key"), input)
+
+	const rsaPacket = RSA.encode(input, "buffer", 2048)
+	assert.equal(Buffer.isBuffer(rsaPacket.encoded), true)
+	assert.equal(rsaPacket.encoding, "buffer")
+	assert.equal(RSA.decode(rsaPacket), input)
+	const rsaUrl = RSA.encode(input, "base64url", 2048)
+	assert.equal(rsaUrl.encoding, "base64url")
+	assert.equal(RSA.decode(rsaUrl), input)
+})
+
+test("crypto base64 variants", () => {
+	const input = "subjects?_d>~\u00ff!"
+	const bytes = Buffer.from(input)
+	const paddedUrl = bytes.toString("base64").replaceAll("+", "-").replaceAll("/", "_")
+	assert.equal(base64.encode(input), bytes.toString("base64"))
+	assert.equal(base64.encode(input, { variant: "url" }), paddedUrl)
+	assert.equal(base64.encode(input, { variant: "url", padding: false }), bytes.toString("base64url"))
+	assert.equal(
+		base64.encode(input, { padding: false }),
+		bytes.toString("base64").replace(/=+$/, ""),
+	)
+
+	const long = "x".repeat(120)
+	const mime = base64.encode(long, { variant: "mime" })
+	const lines = mime.split("\r\n")
+	assert.equal(lines.length, 3)
+	assert.equal(lines[0].length, 76)
+	assert.equal(lines.join(""), Buffer.from(long).toString("base64"))
+	assert.equal(base64.decode(mime, { variant: "mime" }), long)
+	assert.equal(base64.decode(mime.replaceAll("\r\n", "\n"), { variant: "mime" }), long)
+	assert.equal(base64.decode(`${mime.replaceAll("\r\n", "\n")}\n`, { variant: "mime" }), long)
+	assert.throws(
+		() => base64.decode(`${"eHh4".repeat(20)}\neHh4`, { variant: "mime" }),
+		/at most 76 characters/,
+	)
+	assert.throws(() => base64.decode(mime.replaceAll("\r\n", "\r"), { variant: "mime" }))
+	assert.throws(() => base64.decode(mime), /Invalid base64 input/)
+	assert.throws(
+		() => base64.decode(Buffer.from(long).toString("base64"), { variant: "mime" }),
+		/at most 76 characters/,
+	)
+
+	const url = base64.encode(input, { variant: "url", padding: false })
+	assert.equal(base64.decode(url, { variant: "url", padding: false }), input)
+	assert.throws(() => base64.decode(url, { variant: "url" }))
+	assert.throws(() => base64.decode(`${url}=`, { variant: "url", padding: false }))
+
+	assert.throws(() => base64.decode("aGk"), /Invalid base64 input/)
+	assert.throws(() => base64.decode("aGl="), /Invalid base64 input/)
+	assert.equal(base64.decode(paddedUrl, { variant: "url" }), input)
+	assert.throws(() => base64.decode("aG k="), /Invalid base64 input/)
+	assert.equal(base64.decode("aGl=", { mode: "lenient" }), "hi")
+	assert.equal(base64.decode(" aG\nk ", { mode: "lenient" }), "hi")
+	assert.equal(base64.decode(url, { mode: "lenient" }), input)
+	assert.equal(base64.decode(bytes.toString("base64"), { variant: "url", mode: "lenient" }), input)
+	assert.equal(base64.decode(mime.replaceAll("\r\n", "\n"), { variant: "mime", mode: "lenient" }), long)
+
+	assert.throws(() => base64.encode(input, { variant: "base62" }), /variant must be one of/)
+	assert.throws(() => base64.decode("aGk=", { mode: "loose" }), /mode must be one of/)
+})
+
+test("crypto byte-level codecs", () => {
+	const binary = Buffer.from([0x00, 0xff, 0x10, 0x80, 0xfe, 0x7f, 0xc3])
+	for (const [codec, nodeEncoding] of [
+		[base64, "base64"],
+		[abruptCrypto.base16, "hex"],
+		[abruptCrypto.base32, null],
+	]) {
+		const encoded = codec.encode(binary)
+		if (nodeEncoding) assert.equal(encoded, binary.toString(nodeEncoding))
+		assert.throws(() => codec.decode(encoded), /not valid UTF-8/)
+		const decoded = codec.decodeBytes(encoded)
+		assert.equal(Buffer.isBuffer(decoded), true)
+		assert.deepEqual(decoded, binary)
+		assert.deepEqual(codec.decodeBytes(Buffer.from(encoded)), binary)
+		assert.equal(codec.decode(Buffer.from(codec.encode("hello"))), "hello")
+		assert.equal(codec.encode(Buffer.from("hello")), codec.encode("hello"))
+	}
+	assert.equal(abruptCrypto.base32.encode(binary), "AD7RBAH6P7BQ====")
+	assert.deepEqual(
+		base64.decodeBytes(binary.toString("base64url"), { variant: "url", padding: false }),
+		binary,
+	)
+	assert.throws(() => base64.decodeBytes(Buffer.from([0xff, 0xfe])), /valid UTF-8 text/)
+	assert.throws(() => abruptCrypto.base16.decodeBytes("abc"), /Invalid hex input/)
+})
+
+test("crypto base58 and bech32 codecs", () => {
+	const { base58, base58check, bech32, bech32m } = abruptCrypto
+	assert.equal(base58.encode("Hello World!"), "2NEpo7TZRRrLZSi2U")
+	assert.equal(base58.decode("2NEpo7TZRRrLZSi2U"), "Hello World!")
+	const zeros = Buffer.from([0, 0, 0x28, 0x7f, 0xb4, 0xcd])
+	assert.equal(base58.encode(zeros), "11233QC4")
+	assert.deepEqual(base58.decodeBytes("11233QC4"), zeros)
+	assert.equal(base58.encode(Buffer.alloc(0)), "")
+	const flickr = base58.encode("Hello World!", { alphabet: "flickr" })
+	assert.notEqual(flickr, "2NEpo7TZRRrLZSi2U")
+	assert.equal(base58.decode(flickr, { alphabet: "flickr" }), "Hello World!")
+	assert.throws(() => base58.decode("0OIl"), /Invalid base58 input/)
+	assert.throws(() => base58.encode("x", { alphabet: "ripple" }), /alphabet must be one of/)
+
+	const payload = Buffer.from("00010966776006953d5567439e5e39f86a0d273bee", "hex")
+	assert.equal(base58check.encode(payload), "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM")
+	assert.deepEqual(base58check.decodeBytes("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"), payload)
+	assert.throws(() => base58check.decodeBytes("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN"), /checksum mismatch/)
+	assert.throws(() => base58check.decodeBytes("1"), /too short/)
+
+	assert.deepEqual(bech32.decode("A12UEL5L"), { hrp: "a", data: Buffer.alloc(0) })
+	const words = bech32.decode("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw")
+	assert.equal(words.hrp, "abcdef")
+	assert.equal(words.data.length, 20)
+	assert.equal(bech32.encode("abcdef", words.data), "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw")
+	assert.deepEqual(bech32m.decode("A1LQFN3A"), { hrp: "a", data: Buffer.alloc(0) })
+	const mwords = bech32m.decode("abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx")
+	assert.equal(bech32m.encode("abcdef", mwords.data), "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx")
+
+	const encoded = bech32.encode("BC", Buffer.from("hello"))
+	assert.equal(encoded.startsWith("bc1"), true)
+	assert.equal(bech32.decode(encoded.toUpperCase()).data.toString(), "hello")
+	assert.throws(() => bech32m.decode(encoded), /Invalid bech32m input: checksum mismatch/)
+	assert.throws(() => bech32.decode(`${encoded.slice(0, -1)}q`), /checksum mismatch/)
+	assert.throws(() => bech32.decode("A12uEL5L"), /mixed case/)
+	assert.throws(() => bech32.decode("pzry9x0s0muk"), /missing separator/)
+	assert.throws(() => bech32.decode("1pzry9x0s0muk"), /HRP must be 1 to 83 characters/)
+	assert.throws(() => bech32.decode("a1b2uel5l"), /unexpected character 'b' at position 2/)
+	assert.throws(() => bech32.encode("bc", Buffer.alloc(60)), /at most 90 characters/)
+
+	const p2wpkh = Buffer.from("751e76e8199196d454941c45d1b3a323f1433bd6", "hex")
+	const p2wsh = Buffer.from("1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262", "hex")
+	const segwit = [
+		[bech32, "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", "bc", 0, p2wpkh],
+		[bech32, "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7", "tb", 0, p2wsh],
+		[
+			bech32m,
+			"bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+			"bc",
+			1,
+			Buffer.concat([p2wpkh, p2wpkh]),
+		],
+		[bech32m, "bc1sw50qgdz25j", "bc", 16, p2wpkh.subarray(0, 2)],
+		[bech32m, "bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs", "bc", 2, p2wpkh.subarray(0, 16)],
+	]
+	for (const [codec, address, hrp, version, program] of segwit) {
+		assert.deepEqual(codec.decode(address, { witness: true }), { hrp, version, data: program })
+		assert.deepEqual(codec.decode(address.toUpperCase(), { witness: true }).data, program)
+		assert.equal(codec.encode(hrp, program, { version }), address)
+	}
+	assert.throws(() => bech32m.encode("bc", p2wpkh, { version: 0 }), /version 0 must use bech32/)
+	assert.throws(() => bech32.encode("bc", p2wpkh, { version: 1 }), /must use bech32m/)
+	assert.throws(() => bech32m.encode("bc", p2wpkh, { version: 17 }), /between 0 and 16/)
+	assert.throws(() => bech32.encode("bc", p2wpkh.subarray(0, 16), { version: 0 }), /20 or 32 bytes/)
+	assert.throws(() => bech32m.encode("bc", Buffer.alloc(41), { version: 1 }), /2 to 40 bytes/)
+	assert.throws(
+		() => bech32m.decode("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh", { witness: true }),
+		/Invalid bech32m input: Witness version 0 must use bech32/,
+	)
+	assert.throws(
+		() =>
+			bech32.decode("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd", {
+				witness: true,
+			}),
+		/must use bech32m/,
+	)
+})
+
+test("crypto base85 codecs", () => {
+	const { base85 } = abruptCrypto
+	assert.equal(base85.encode("hello world"), "<~BOu!rD]j7BEbo7~>")
+	assert.equal(base85.decode("<~BOu!rD]j7BEbo7~>"), "hello world")
+	assert.equal(base85.decode("BOu!r D]j7B\nEbo7"), "hello world")
+	assert.equal(base85.encode(Buffer.from([0, 0, 0, 0, 0x61, 0x62])), "<~z@:B~>")
+	assert.deepEqual(base85.decodeBytes("<~z@:B~>"), Buffer.from([0, 0, 0, 0, 0x61, 0x62]))
+	assert.throws(() => base85.decode("<~@z~>"), /'z' inside a group/)
+	assert.throws(() => base85.decode("<~B~>"), /final group is too short/)
+	assert.throws(() => base85.decode("<~uuuuu~>"), /overflows 32 bits/)
+
+	const z85Bytes = Buffer.from([0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b])
+	assert.equal(base85.encode(z85Bytes, { variant: "z85" }), "HelloWorld")
+	assert.deepEqual(base85.decodeBytes("HelloWorld", { variant: "z85" }), z85Bytes)
+	assert.throws(() => base85.encode("abc", { variant: "z85" }), /multiple of 4 bytes/)
+	assert.throws(() => base85.decodeBytes("Hell", { variant: "z85" }), /multiple of 5/)
+
+	assert.equal(base85.encode("hello", { variant: "rfc1924" }), "Xk~0{Zv")
+	assert.equal(base85.decode("Xk~0{Zv", { variant: "rfc1924" }), "hello")
+	assert.throws(() => base85.decode("Xk\"0", { variant: "rfc1924" }), /unexpected character/)
+
+	const binary = crypto.randomBytes(37)
+	for (const variant of ["ascii85", "rfc1924"]) {
+		assert.deepEqual(base85.decodeBytes(base85.encode(binary, { variant }), { variant }), binary)
+	}
+	assert.throws(() => base85.encode("x", { variant: "btoa" }), /variant must be one of/)
+})
+
+test("crypto base32 variants", () => {
+	const { base32 } = abruptCrypto
+	assert.equal(base32.encode("foobar"), "MZXW6YTBOI======")
+	assert.equal(base32.encode("foobar", { padding: false }), "MZXW6YTBOI")
+	assert.equal(base32.encode("foobar", { variant: "hex" }), "CPNMUOJ1E8======")
+	assert.equal(base32.encode("foobar", { variant: "crockford" }), "CSQPYRK1E8")
+	assert.equal(base32.encode("foobar", { variant: "zbase32" }), "c3zs6aubqe")
+	assert.equal(base32.decode("CPNMUOJ1E8======", { variant: "hex" }), "foobar")
+	assert.equal(base32.decode("c3zs6aubqe", { variant: "zbase32" }), "foobar")
+	assert.equal(base32.decode("csqp-yrkl-e8", { variant: "crockford" }), "foobar")
+	assert.equal(base32.decode("CSQPYRKIE8", { variant: "crockford", mode: "strict" }), "foobar")
+	assert.equal(base32.decode("CSqPYRKLE8", { variant: "crockford" }), "foobar")
+
+	assert.equal(base32.decode("mzxw6ytboi"), "foobar")
+	assert.equal(base32.decode("MZXW6YTBOI======garbage"), "foobar")
+	const strict = { mode: "strict" }
+	assert.equal(base32.decode("MZXW6YTBOI======", strict), "foobar")
+	assert.throws(() => base32.decode("MZXW6YTBOI======garbage", strict), /unexpected data after padding/)
+	assert.throws(() => base32.decode("MZXW6YTBOI", strict), /incorrect padding/)
+	assert.throws(() => base32.decode("MZXW6YTBOI====", strict), /incorrect padding/)
+	assert.throws(() => base32.decode("MZXW6YTBOI==============", strict), /incorrect padding/)
+	assert.throws(() => base32.decode("MZXW6YTBOJ======", strict), /non-zero trailing bits/)
+	assert.throws(() => base32.decode("MZXW6YTBO=======", strict), /incorrect length/)
+	assert.throws(() => base32.decode("mzxw6ytboi======", strict), /Invalid base32 input/)
+	assert.throws(() => base32.decode("MZXW 6YTBOI======", strict), /Invalid base32 input/)
+	assert.equal(base32.decode("MZXW6YTBOI", { mode: "strict", padding: false }), "foobar")
+	assert.throws(() => base32.decode("MZXW6YTBOI======", { mode: "strict", padding: false }), /padding is not allowed/)
+
+	const binary = crypto.randomBytes(23)
+	for (const variant of ["rfc4648", "hex", "crockford", "zbase32"]) {
+		const encoded = base32.encode(binary, { variant })
+		assert.deepEqual(base32.decodeBytes(encoded, { variant, mode: "strict" }), binary)
+	}
+	assert.throws(() => base32.encode("x", { variant: "geohash" }), /variant must be one of/)
+})
+
+test("crypto baseN codec", () => {
+	const { baseN } = abruptCrypto
+	assert.equal(baseN.encode(255n, "base36"), "73")
+	assert.equal(baseN.encode(0n, "base62"), "0")
+	assert.equal(baseN.encode(2n ** 64n, "base36"), (2n ** 64n).toString(36))
+	const id = 123456789012345678901234567890n
+	assert.equal(baseN.encode(id, "base36"), id.toString(36))
+	assert.equal(baseN.decodeBigInt(id.toString(36), "base36"), id)
+	assert.equal(baseN.decodeBigInt("0", "base62"), 0n)
+	assert.equal(baseN.encode(61n, "base62"), "z")
+	assert.equal(baseN.encode(62n, "base62"), "10")
+	assert.equal(baseN.encode(Buffer.from([0xff]), "01"), "11111111")
+
+	const padded = Buffer.from([0, 0, 1, 2, 3])
+	const encoded = baseN.encode(padded, "base62")
+	assert.equal(encoded.startsWith("00"), true)
+	assert.deepEqual(baseN.decodeBytes(encoded, "base62"), padded)
+	assert.equal(baseN.decode(baseN.encode("hello", "🙂🙃😀"), "🙂🙃😀"), "hello")
+	assert.equal(baseN.encode("hello", "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"), abruptCrypto.base58.encode("hello"))
+
+	assert.throws(() => baseN.encode(-1n, "base36"), /must not be negative/)
+	assert.throws(() => baseN.encode("x", "a"), /between 2 and 256/)
+	assert.throws(() => baseN.encode("x", "abca"), /duplicate characters/)
+	assert.throws(() => baseN.decode("xyz!", "base36"), /Invalid baseN input/)
+})
+
+test("crypto web and mail codecs", () => {
+	const { percent, html, quotedPrintable, encodedWord } = abruptCrypto
+	const text = "a b/c?d=é&~"
+	assert.equal(percent.encode(text), encodeURIComponent(text).replace(/[!'()*]/g, (ch) => `%${ch.charCodeAt(0).toString(16).toUpperCase()}`))
+	assert.equal(percent.encode("/a b/@:!'()*"), "%2Fa%20b%2F%40%3A%21%27%28%29%2A")
+	assert.equal(percent.encode("/a b/@:!'()*", { mode: "path" }), "/a%20b/@:!'()*")
+	assert.equal(percent.decode("a%20b%2Fc%C3%A9"), "a b/cé")
+	assert.equal(percent.decode("a+b"), "a+b")
+	assert.deepEqual(percent.decodeBytes("%00%FF"), Buffer.from([0, 255]))
+	assert.equal(percent.encode(Buffer.from([0, 255])), "%00%FF")
+	assert.throws(() => percent.decode("%zz"), /malformed escape at position 0/)
+	assert.throws(() => percent.decode("abc%4"), /malformed escape at position 3/)
+	assert.throws(() => percent.encode("x", { mode: "query" }), /mode must be one of/)
+
+	assert.equal(html.encode(`<a href="x">Tom & 'Jerry'</a>`), "&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;")
+	assert.equal(html.encode("<é>", { mode: "numeric" }), "&#60;é&#62;")
+	assert.equal(html.encode("café © 😀", { ascii: true }), "caf&eacute; &copy; &#128512;")
+	assert.equal(html.decode("&lt;p&gt;caf&eacute; &#233; &#xE9; &#X1F600; &copy;&hellip;&lt;/p&gt;"), "<p>café é é 😀 ©…</p>")
+	assert.equal(html.decode("AT&T &unknown; & &#xZZ; &amp"), "AT&T &unknown; & &#xZZ; &amp")
+	assert.equal(html.decode("&#1114112;"), "\ufffd")
+	assert.equal(html.decode(html.encode("<\"'&>", { ascii: true })), "<\"'&>")
+
+	assert.equal(quotedPrintable.encode("caf\u00e9 = ok"), "caf=C3=A9 =3D ok")
+	assert.equal(quotedPrintable.encode("trailing \nnext"), "trailing=20\r\nnext")
+	const long = "x".repeat(100)
+	const wrapped = quotedPrintable.encode(long)
+	assert.equal(wrapped.split("\r\n").every((line) => line.length <= 76), true)
+	assert.equal(wrapped.includes("=\r\n"), true)
+	assert.equal(quotedPrintable.decode(wrapped), long)
+	assert.equal(quotedPrintable.decode("caf=C3=A9 =3D ok"), "café = ok")
+	assert.equal(quotedPrintable.decode("soft=\nbreak  \r\nline"), "softbreak\r\nline")
+	assert.deepEqual(quotedPrintable.decodeBytes(quotedPrintable.encode(Buffer.from([0, 13, 255]))), Buffer.from([0, 13, 255]))
+	const binary = Buffer.from([0x61, 0x0a, 0x62, 0x0d])
+	assert.equal(quotedPrintable.encode(binary), "a=0Ab=0D")
+	assert.deepEqual(quotedPrintable.decodeBytes(quotedPrintable.encode(binary)), binary)
+	const mixed = Buffer.from("line one \r\nline\ttwo\n\rthree \n")
+	assert.deepEqual(quotedPrintable.decodeBytes(quotedPrintable.encode(mixed)), mixed)
+	const random = crypto.randomBytes(600)
+	assert.deepEqual(quotedPrintable.decodeBytes(quotedPrintable.encode(random)), random)
+	assert.equal(quotedPrintable.encode("a\nb"), "a\r\nb")
+	assert.equal(quotedPrintable.decode(quotedPrintable.encode("a\nb", { binary: true })), "a\nb")
+	assert.equal(quotedPrintable.encode(Buffer.from("a\nb"), { binary: false }), "a\r\nb")
+	assert.throws(() => quotedPrintable.decode("bad=ZZ"), /malformed escape/)
+
+	assert.equal(encodedWord.encode("café"), "=?UTF-8?B?Y2Fmw6k=?=")
+	assert.equal(encodedWord.encode("café au lait", { encoding: "Q" }), "=?UTF-8?Q?caf=C3=A9_au_lait?=")
+	const subject = "Ünïcödé ".repeat(10)
+	const encoded = encodedWord.encode(subject)
+	assert.equal(encoded.split(" ").every((word) => word.length <= 75), true)
+	assert.equal(encodedWord.decode(encoded), subject)
+	assert.equal(encodedWord.decode("Re: =?UTF-8?Q?caf=C3=A9?= =?ISO-8859-1?B?6Q==?= done"), "Re: caféé done")
+	assert.equal(encodedWord.decode("=?utf-8?q?a_b?=   plain"), "a b   plain")
+	assert.equal(encodedWord.decode("not =?encoded"), "not =?encoded")
+	assert.equal(encodedWord.decode("=?UTF-8?Q?hello_?="), "hello ")
+	assert.equal(encodedWord.decode("=?UTF-8?Q?a=3D_?="), "a= ")
+	assert.equal(encodedWord.decode(encodedWord.encode("a  ", { encoding: "Q" })), "a  ")
+	const spaced = `${"Déjà vu, naïve café ".repeat(6)}  `
+	const qWords = encodedWord.encode(spaced, { encoding: "Q" })
+	assert.ok(qWords.split(" ").length > 1)
+	assert.ok(qWords.split(" ").every((word) => word.length <= 75))
+	assert.equal(encodedWord.decode(qWords), spaced)
+	assert.throws(() => encodedWord.decode("=?UTF-8?Q?bad=4?="), /malformed escape/)
+	assert.throws(() => encodedWord.decode("=?KOI8-R?B?AA==?="), /Unsupported encoded-word charset/)
+	assert.throws(() => encodedWord.encode("x", { encoding: "X" }), /must be one of: B, Q/)
+})
+
+test("crypto morse tables and prosigns", () => {
+	const { morse } = abruptCrypto
+	assert.equal(morse.encode("CQ <AR> <sk>"), "-.-. --.- / .-.-. / ...-.-")
+	assert.equal(morse.decode("-.-. --.- / .-.-. / ...-.- -...-"), "cq + <SK>=")
+	assert.equal(morse.decode("-.-. --.- / .-.-. / ...-.- -...-", { prosigns: true }), "cq <AR> <SK><BT>")
+	assert.equal(morse.encode("<xx>"), "-..- -..-")
+
+	assert.equal(morse.encode("Ñandú", { table: "latin" }), "--.-- .- -. -..")
+	assert.equal(morse.decode(morse.encode("çà", { table: "latin" }), { table: "latin" }), "çà")
+	assert.equal(morse.decode(morse.encode("Привет мир 2024", { table: "cyrillic" }), { table: "cyrillic" }), "привет мир 2024")
+	assert.equal(morse.decode(morse.encode("Καλημέρα", { table: "greek" }), { table: "greek" }), "καλημερα")
+	assert.equal(morse.encode("ガ", { table: "wabun" }), ".-.. ..")
+	assert.equal(morse.decode(morse.encode("こんにちは パン", { table: "wabun" }), { table: "wabun" }), "コンニチハ パン")
+	for (const table of ["cyrillic", "greek", "wabun"]) {
+		assert.equal(morse.encode("2024 ?", { table, strict: true }), "..--- ----- ..--- ....- / ..--..")
+		assert.equal(morse.decode("..--- ----- / ..--..", { table, strict: true }), "20 ?")
+	}
+
+	const table = { "☃": "..--..--" }
+	assert.equal(morse.decode(morse.encode("a☃b", { table }), { table }), "a☃b")
+	assert.equal(morse.encode("héllo"), ".... .-.. .-.. ---")
+	assert.throws(() => morse.encode("héllo", { strict: true }), /Cannot encode character 'é' at position 1/)
+	assert.throws(() => morse.decode("... --.--.-- ...", { strict: true }), /Unknown Morse code '--.--.--'/)
+	assert.throws(() => morse.encode("x", { table: { ab: "." } }), /single characters/)
+	assert.throws(() => morse.encode("x", { table: { a: ".x" } }), /only '.' and '-'/)
+	assert.throws(() => morse.encode("x", { table: "klingon" }), /must be one of: itu, latin, cyrillic, greek, wabun/)
+})
+
+test("crypto morse audio", () => {
+	const { morse } = abruptCrypto
+	const wav = morse.toAudio("paris")
+	assert.equal(Buffer.isBuffer(wav), true)
+	assert.equal(wav.subarray(0, 4).toString(), "RIFF")
+	assert.equal(wav.subarray(8, 16).toString(), "WAVEfmt ")
+	assert.equal(wav.readUInt32LE(24), 44100)
+	assert.equal(wav.readUInt16LE(34), 16)
+	assert.equal(wav.readUInt32LE(40), wav.length - 44)
+	assert.equal((wav.length - 44) / 2, Math.round(43 * 0.06 * 44100))
+
+	const pcm = morse.toAudio("paris paris", { format: "pcm", sampleRate: 8000, wpm: 12, volume: 0.5 })
+	assert.equal(pcm instanceof Float32Array, true)
+	assert.equal(pcm.length, (43 * 2 + 7) * 0.1 * 8000)
+	assert.equal(pcm[0], 0)
+	assert.equal(Math.abs(pcm[Math.round(0.05 * 8000)]) <= 0.5, true)
+	assert.equal(pcm.reduce((max, sample) => Math.max(max, Math.abs(sample)), 0) > 0.49, true)
+
+	const slow = morse.toAudio("paris paris", { format: "pcm", sampleRate: 8000, farnsworth: 10 })
+	assert.equal(Math.abs(slow.length / 8000 - (12 - (7 * 4.14) / 19)) < 0.01, true)
+
+	const target = "scripts/tmp-morse-audio/sos.wav"
+	const written = morse.toAudio("sos", { path: target })
+	assert.deepEqual(fs.readFileSync(target), written)
+	fs.rmSync("scripts/tmp-morse-audio", { recursive: true, force: true })
+
+	assert.throws(() => morse.toAudio("e", { farnsworth: 30 }), /at most wpm/)
+	assert.throws(() => morse.toAudio("e", { volume: 2 }), /volume must be between 0 and 1/)
+	assert.throws(() => morse.toAudio("e", { frequency: 30000 }), /below half the sampleRate/)
+	assert.throws(() => morse.toAudio("e", { format: "mp3" }), /must be one of: wav, pcm/)
+	assert.throws(() => morse.toAudio("e", { path: "../x.wav" }), /Path traversal/)
+})
+
+test("crypto morse timing and audio decoding", () => {
+	const { morse } = abruptCrypto
+	const unit = 60
+	assert.equal(morse.decodeTimings([unit, unit, unit, unit, unit, 3 * unit, 180, unit, 180, unit, 180, 3 * unit, unit, unit, unit, unit, unit]), "sos")
+
+	let seed = 1
+	const jitter = () => {
+		seed = (seed * 16807) % 2147483647
+		return 0.85 + (0.3 * seed) / 2147483647
+	}
+	const timings = []
+	for (const [wordIndex, word] of morse.encode("hello world").split(" / ").entries()) {
+		if (wordIndex > 0) timings[timings.length - 1] = 7 * unit * jitter()
+		for (const [letterIndex, letter] of word.split(" ").entries()) {
+			if (letterIndex > 0) timings[timings.length - 1] = 3 * unit * jitter()
+			for (const element of letter) timings.push((element === "." ? unit : 3 * unit) * jitter(), unit * jitter())
+		}
+	}
+	timings.pop()
+	assert.equal(morse.decodeTimings(timings), "hello world")
+	assert.equal(morse.decodeTimings([]), "")
+	assert.throws(() => morse.decodeTimings([1, -1]), /non-negative finite numbers/)
+
+	assert.equal(morse.decodeAudio(morse.toAudio("cq de k1abc <sk>"), undefined, { prosigns: true }), "cq de k1abc <SK>")
+	const pangram = "the quick brown fox jumps over the lazy dog 0123"
+	const pcm = morse.toAudio(pangram, { wpm: 30, frequency: 850, sampleRate: 8000, format: "pcm" })
+	assert.equal(morse.decodeAudio(pcm, 8000), pangram)
+	assert.equal(morse.decodeAudio(pcm, 8000, { frequency: 850 }), pangram)
+	assert.equal(morse.decodeAudio(morse.toAudio("paris paris paris", { farnsworth: 12, format: "pcm" }), 44100), "paris paris paris")
+
+	const sweep = "the quick brown fox 0123"
+	for (const [wpm, sampleRate] of [[5, 8000], [12, 8000], [20, 22050], [35, 44100], [60, 8000], [60, 44100], [80, 22050], [100, 44100]]) {
+		for (const frequency of [300, 440, 700, 1500]) {
+			const pcm = morse.toAudio(sweep, { wpm, frequency, sampleRate, format: "pcm" })
+			assert.equal(morse.decodeAudio(pcm, sampleRate), sweep, `${wpm} wpm at ${frequency} Hz`)
+			assert.equal(morse.decodeAudio(pcm, sampleRate, { frequency }), sweep, `${wpm} wpm at ${frequency} Hz`)
+		}
+	}
+	assert.equal(morse.decodeAudio(morse.toAudio(pangram, { wpm: 100 })), pangram)
+
+	const noisy = morse.toAudio("noise test", { format: "pcm", sampleRate: 16000, volume: 0.4 })
+	for (let index = 0; index < noisy.length; index += 1) noisy[index] += (jitter() - 1) * 0.5
+	assert.equal(morse.decodeAudio(noisy, 16000), "noise test")
+	assert.equal(morse.decodeAudio(new Float32Array(1000), 8000), "")
+	assert.throws(() => morse.decodeAudio(new Float32Array(1000)), /sampleRate is required/)
+	assert.throws(() => morse.decodeAudio(Buffer.from("nope")), /Unsupported WAV data/)
+})
+
+test("crypto classic ciphers", () => {
+	const { classic } = abruptCrypto
+	assert.equal(classic.vigenere.encrypt("Attack at dawn!", "LEMON"), "Lxfopv ef rnhr!")
+	assert.equal(classic.vigenere.decrypt("Lxfopv ef rnhr!", "lemon"), "Attack at dawn!")
+	assert.equal(classic.beaufort.encrypt("DEFENDTHEEASTWALLOFTHECASTLE", "FORTIFICATION"), "CKMPVCPVWPIWUJOGIUAPVWRIWUUK")
+	assert.equal(classic.beaufort.decrypt("CKMPVCPVWPIWUJOGIUAPVWRIWUUK", "FORTIFICATION"), "DEFENDTHEEASTWALLOFTHECASTLE")
+	assert.equal(classic.autokey.encrypt("ATTACKATDAWN", "QUEENLY"), "QNXEPVYTWTWP")
+	assert.equal(classic.autokey.decrypt("QNXEPVYTWTWP", "QUEENLY"), "ATTACKATDAWN")
+	assert.equal(classic.atbash.encrypt("Abc xyz"), "Zyx cba")
+	assert.equal(classic.atbash.decrypt("Zyx cba"), "Abc xyz")
+	assert.equal(classic.affine.encrypt("AFFINE CIPHER", 5, 8), "IHHWVC SWFRCP")
+	assert.equal(classic.affine.decrypt("IHHWVC SWFRCP", 5, 8), "AFFINE CIPHER")
+	const letters = "abcdefghijklmnopqrstuvwxyz"
+	assert.equal(classic.affine.encrypt(letters, 25, 7), "hgfedcbazyxwvutsrqponmlkji")
+	for (const a of [1, 3, 5, 7, 9, 11, 15, 17, 19, 21, 23, 25]) {
+		const encrypted = classic.affine.encrypt(`${letters} ${letters.toUpperCase()}`, a, 19)
+		assert.equal(new Set(encrypted.slice(0, 26)).size, 26)
+		for (const [index, letter] of [...encrypted.slice(0, 26)].entries()) {
+			assert.equal(letter.charCodeAt(0) - 97, (a * index + 19) % 26)
+		}
+		assert.equal(classic.affine.decrypt(encrypted, a, 19), `${letters} ${letters.toUpperCase()}`)
+	}
+	const alphabet = "QWERTYUIOPASDFGHJKLZXCVBNM"
+	assert.equal(classic.substitution.encrypt("Hello", alphabet), "Itssg")
+	assert.equal(classic.substitution.decrypt("Itssg", alphabet), "Hello")
+	assert.equal(classic.playfair.encrypt("Hide the gold in the tree stump", "playfair example"), "BMODZBXDNABEKUDMUIXMMOUVIF")
+	assert.equal(classic.playfair.decrypt("BMODZBXDNABEKUDMUIXMMOUVIF", "playfair example"), "HIDETHEGOLDINTHETREXESTUMP")
+	assert.equal(classic.railFence.encrypt("WEAREDISCOVEREDFLEEATONCE", 3), "WECRLTEERDSOEEFEAOCAIVDEN")
+	assert.equal(classic.railFence.decrypt("WECRLTEERDSOEEFEAOCAIVDEN", 3), "WEAREDISCOVEREDFLEEATONCE")
+	assert.equal(classic.railFence.decrypt(classic.railFence.encrypt("hello world", 4, 2), 4, 2), "hello world")
+	assert.equal(classic.columnar.encrypt("WEAREDISCOVEREDFLEEATONCE", "ZEBRAS"), "EVLNACDTESEAROFODEECWIREE")
+	assert.equal(classic.columnar.decrypt("EVLNACDTESEAROFODEECWIREE", "ZEBRAS"), "WEAREDISCOVEREDFLEEATONCE")
+	assert.equal(classic.bacon.encrypt("Stega"), "BAAAB BAABA AABAA AABBA AAAAA")
+	assert.equal(classic.bacon.decrypt("baaab baaba aabaa aabba aaaaa"), "STEGA")
+	assert.equal(classic.bacon.decrypt(classic.bacon.encrypt("jive", { alphabet: "full" }), { alphabet: "full" }), "JIVE")
+	assert.equal(classic.bacon.decrypt(classic.bacon.encrypt("jive")), "IIUE")
+
+	assert.throws(() => classic.vigenere.encrypt("x", "123"), /at least one letter/)
+	assert.throws(() => classic.affine.encrypt("x", 13, 8), /coprime with 26/)
+	assert.throws(() => classic.substitution.encrypt("x", "ABC"), /permutation of the 26 letters/)
+	assert.throws(() => classic.playfair.decrypt("ABC", "key"), /even number of letters/)
+	assert.throws(() => classic.railFence.encrypt("x", 1), /at least 2 rails/)
+	assert.throws(() => classic.bacon.decrypt("AAAA"), /groups of 5/)
+	assert.throws(() => classic.bacon.decrypt("BBBBB"), /outside the alphabet/)
+})
+
+test("crypto cryptanalysis", () => {
+	const { crack, analysis, classic } = abruptCrypto
+	const plain = "It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch of incredulity, it was the season of Light, it was the season of Darkness."
+
+	const candidates = crack.rot(abruptCrypto.rot(plain, 7))
+	assert.equal(candidates.length, 26)
+	assert.equal(candidates[0].shift, 7)
+	assert.equal(candidates[0].text, plain)
+	assert.equal(candidates.every((entry, index) => index === 0 || entry.score <= candidates[index - 1].score), true)
+	assert.deepEqual(crack.rot("Uryyb jbeyq")[0], { shift: 13, text: "Hello world", score: crack.rot("Uryyb jbeyq")[0].score })
+
+	for (const key of ["LEMON", "KEY", "CRYPTOGRAPHY"]) {
+		const result = crack.vigenere(classic.vigenere.encrypt(plain, key))
+		assert.equal(result.key, key)
+		assert.equal(result.keyLength, key.length)
+		assert.equal(result.plaintext, plain)
+	}
+	for (const [sentence, key] of [
+		["Meet me at the park at noon tomorrow", "SUN"],
+		["Bring the documents to the second floor office", "KEY"],
+		["The committee will publish its findings next week after the final review", "CODE"],
+	]) {
+		const result = crack.vigenere(classic.vigenere.encrypt(sentence, key))
+		assert.equal(result.key, key)
+		assert.equal(result.plaintext, sentence)
+	}
+	let seed = 7
+	let noise = ""
+	for (let index = 0; index < 10000; index += 1) {
+		seed = (seed * 16807) % 2147483647
+		noise += String.fromCharCode(97 + (seed % 26)) + (seed % 5 === 0 ? " " : "")
+	}
+	const started = performance.now()
+	const noisy = crack.vigenere(noise, { maxKeyLength: 100 })
+	assert.equal(noisy.keyLength <= 100, true)
+	assert.equal(performance.now() - started < 5000, true)
+
+	const spaced = crack.rot("a b c")
+	assert.equal(spaced.length, 26)
+	assert.equal(spaced.every((entry) => entry.score === -10), true)
+	assert.equal(JSON.stringify(spaced).includes("null"), false)
+	assert.equal(crack.vigenere("a b c").score, -10)
+	assert.throws(() => crack.vigenere("1234"), /must contain letters/)
+	assert.throws(() => crack.vigenere("abc", { maxKeyLength: 0 }), /maxKeyLength must be between 1 and 100/)
+
+	const report = analysis.frequency("Hello, World")
+	assert.equal(report.total, 10)
+	assert.deepEqual(report.letters[0], { letter: "l", count: 3, frequency: 0.3 })
+	assert.deepEqual(report.letters.map((entry) => entry.letter).slice(1, 3), ["o", "d"])
+	assert.equal(report.indexOfCoincidence, Math.round(((3 * 2 + 2 * 1) / (10 * 9)) * 10000) / 10000)
+	assert.equal(report.chiSquared > 0, true)
+	assert.deepEqual(analysis.frequency("123"), { total: 0, letters: [], indexOfCoincidence: 0, chiSquared: 0 })
+})
+
+test("crypto rot variants", () => {
+	const { rot } = abruptCrypto
+	const input = "Hello World!"
+	assert.equal(rot(input, 0), input)
+	assert.equal(rot(input, 26), input)
+	assert.equal(rot(input, -3), "Ebiil Tloia!")
+	assert.equal(rot(rot(input, 1000003), -1000003), input)
+	assert.equal(rot("Call 555-0199", undefined, { alphabet: "rot5" }), "Call 000-5644")
+	assert.equal(rot("Call 555-0199", undefined, { alphabet: "rot18" }), "Pnyy 000-5644")
+	assert.equal(rot(rot("Call 555-0199", undefined, { alphabet: "rot18" }), undefined, { alphabet: "rot18" }), "Call 555-0199")
+	assert.equal(rot("The Quick Brown Fox Jumps Over The Lazy Dog.", undefined, { alphabet: "rot47" }), '%96 "F:4< qC@H? u@I yF>AD ~G6C %96 {2KJ s@8]')
+	for (const alphabet of ["rot13", "rot5", "rot18", "rot47", "αβγδε"]) {
+		assert.equal(rot(rot("Zaphod 42! αβε", 7, { alphabet }), -7, { alphabet }), "Zaphod 42! αβε")
+	}
+	assert.equal(rot("abcxyz", 2, { alphabet: "abcxyz" }), "cxyzab")
+	assert.equal(rot("αβγ", undefined, { alphabet: "αβγδ" }), "γδα")
+	assert.throws(() => rot("x", 1, { alphabet: "aa" }), /duplicate characters/)
+	assert.throws(() => rot("x", 1, { alphabet: "a" }), /at least 2 characters/)
+})
+
+test("crypto codec detection", () => {
+	const message = "hello world, this is a secret message for the security team"
+	const hex = Buffer.from(abruptCrypto.rot(message)).toString("hex")
+	const wrapped = Buffer.from(hex).toString("base64")
+	const detected = abruptCrypto.detect(wrapped)
+	assert.equal(detected[0].encoding, "base64")
+	assert.equal(detected[0].decoded, hex)
+	assert.equal(detected.every((entry, index) => index === 0 || entry.score <= detected[index - 1].score), true)
+	assert.deepEqual(abruptCrypto.unwrap(wrapped), { output: message, layers: ["base64", "hex", "rot13"] })
+	assert.deepEqual(abruptCrypto.unwrap(wrapped, { maxDepth: 2 }), { output: abruptCrypto.rot(message), layers: ["base64", "hex"] })
+
+	assert.equal(abruptCrypto.detect("48656c6c6f")[0].encoding, "hex")
+	assert.equal(abruptCrypto.detect(abruptCrypto.base32.encode("hello there"))[0].encoding, "base32")
+	assert.equal(abruptCrypto.detect("aGk_Pz8-")[0].encoding, "base64url")
+	assert.equal(abruptCrypto.detect("a%20b%2Fc")[0].decoded, "a b/c")
+	assert.deepEqual(abruptCrypto.unwrap(abruptCrypto.morse.encode("sos help")), { output: "sos help", layers: ["morse"] })
+	assert.deepEqual(abruptCrypto.unwrap("plain words"), { output: "plain words", layers: [] })
+
+	const pangram = "The quick brown fox jumps over the lazy dog and keeps running far away"
+	const rotated = abruptCrypto.detect(abruptCrypto.rot(pangram))
+	assert.equal(rotated[0].encoding, "rot13")
+	assert.equal(rotated[0].decoded, pangram)
+	const report = "Please send the quarterly report to finance by Friday"
+	const layered = Buffer.from(Buffer.from(abruptCrypto.rot(report)).toString("hex")).toString("base64")
+	assert.deepEqual(abruptCrypto.unwrap(layered), { output: report, layers: ["base64", "hex", "rot13"] })
+	for (const [sentence, shift] of [
+		["Meet me at the station before the train leaves", 3],
+		["The meeting moved to Thursday afternoon", 7],
+		["Can you review the pull request when you have time", 20],
+	]) {
+		assert.deepEqual(abruptCrypto.unwrap(abruptCrypto.rot(sentence, shift)), {
+			output: sentence,
+			layers: [`rot${shift}`],
+		})
+	}
+	assert.deepEqual(abruptCrypto.unwrap(pangram), { output: pangram, layers: [] })
+	assert.equal(abruptCrypto.detect("SGVsbG8gd29ybGQ").some((entry) => entry.encoding.startsWith("rot")), false)
+	assert.deepEqual(abruptCrypto.detect(""), [])
+	assert.throws(() => abruptCrypto.unwrap("x", { maxDepth: 0 }), /maxDepth must be between 1 and 64/)
+})
+
+test("crypto blake hashes", () => {
+	const input = "Hello World!"
+	const expectedBlake2b = crypto
+		.createHash("blake2b512")
+		.update(input)
+		.digest("hex")
+	const expectedBlake2s = crypto
+		.createHash("blake2s256")
+		.update(input)
+		.digest("base64")
+	assert.equal(abruptCrypto.blake2b(input), expectedBlake2b)
+	assert.equal(abruptCrypto.blake2b(Buffer.from(input)), expectedBlake2b)
+	assert.equal(abruptCrypto.blake2s(input, { encoding: "base64" }), expectedBlake2s)
+	assert.equal(abruptCrypto.blake2b(input, { length: 20 }).length, 40)
+
+	const kat = Buffer.from(Array.from({ length: 64 }, (_, i) => i))
+	assert.equal(
+		abruptCrypto.blake2b("", { key: kat }),
+		"10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568",
+	)
+	assert.throws(() => abruptCrypto.blake2s(input, { length: 33 }))
+	assert.throws(() => abruptCrypto.blake2s(input, { key: kat }))
+
+	assert.equal(
+		abruptCrypto.blake3(""),
+		"af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262",
+	)
+	const large = Buffer.alloc(1 << 20, 7)
+	assert.equal(abruptCrypto.blake3(large), abruptCrypto.blake3(large.toString("latin1")))
+	assert.equal(abruptCrypto.blake3(input, { length: 64 }).length, 128)
+
+	const key = Buffer.alloc(32, 1)
+	const keyed = abruptCrypto.blake3(input, { key })
+	assert.notEqual(keyed, abruptCrypto.blake3(input))
+	assert.notEqual(abruptCrypto.blake3(input, { context: "abrupt 2026 test" }), keyed)
+	assert.throws(() => abruptCrypto.blake3(input, { key: "short" }))
+	assert.throws(() => abruptCrypto.blake3(input, { key, context: "ctx" }))
+})
+
+test("crypto hmac", () => {
+	const key = "whsec_test"
+	const message = '{"event":"push"}'
+	for (const algorithm of ["md5", "sha1", "sha256", "sha512", "sha3_256"]) {
+		const nodeAlgorithm = algorithm.replace("_", "-")
+		const expectedHex = crypto
+			.createHmac(nodeAlgorithm, key)
+			.update(message)
+			.digest("hex")
+		const expectedBase64 = crypto
+			.createHmac(nodeAlgorithm, key)
+			.update(message)
+			.digest("base64")
+		assert.equal(abruptCrypto.hmac(algorithm, key, message), expectedHex)
+		assert.equal(
+			abruptCrypto.hmac(algorithm, Buffer.from(key), message, "base64"),
+			expectedBase64,
+		)
+		assert.equal(abruptCrypto.hmacVerify(algorithm, key, message, expectedHex), true)
+		assert.equal(
+			abruptCrypto.hmacVerify(algorithm, key, message, expectedBase64, "base64"),
+			true,
+		)
+	}
+
+	const signature = abruptCrypto.hmac("sha256", key, message)
+	assert.equal(abruptCrypto.hmacVerify("sha256", key, `${message} `, signature), false)
+	assert.equal(abruptCrypto.hmacVerify("sha256", key, message, signature.slice(2)), false)
+	assert.equal(abruptCrypto.hmacVerify("sha256", key, message, "not a signature"), false)
+	assert.throws(() => abruptCrypto.hmac("sha999", key, message))
+
+	const stream = new abruptCrypto.Hmac("sha256", key)
+	stream.update('{"event":')
+	stream.update(Buffer.from('"push"}'))
+	assert.equal(stream.digest(), signature)
+	assert.throws(() => stream.update("more"))
+	assert.throws(() => stream.digest())
+})
+
+test("crypto constant-time comparison", () => {
+	const token = "tok_4f9a1c"
+	assert.equal(abruptCrypto.timingSafeEqual(token, token), true)
+	assert.equal(abruptCrypto.timingSafeEqual(token, Buffer.from(token)), true)
+	assert.equal(abruptCrypto.timingSafeEqual(token, "tok_4f9a1d"), false)
+	assert.equal(abruptCrypto.timingSafeEqual(token, `${token}\0`), false)
+	assert.equal(abruptCrypto.timingSafeEqual("", ""), true)
+	assert.equal(abruptCrypto.timingSafeEqual("", "a"), false)
+
+	const input = "Hello World!"
+	const digest = abruptCrypto.sha256(input)
+	assert.equal(abruptCrypto.verifyDigest(input, digest), true)
+	assert.equal(abruptCrypto.verifyDigest(Buffer.from(input), digest), true)
+	assert.equal(
+		abruptCrypto.verifyDigest(input, abruptCrypto.sha512(input, "base64"), "sha512"),
+		true,
+	)
+	assert.equal(
+		abruptCrypto.verifyDigest(input, abruptCrypto.md5(input), "md5", "hex"),
+		true,
+	)
+	assert.equal(abruptCrypto.verifyDigest(input, digest.slice(0, 32)), false)
+	assert.equal(abruptCrypto.verifyDigest(input, `${digest}00`), false)
+	assert.equal(abruptCrypto.verifyDigest(`${input}.`, digest), false)
+	assert.equal(abruptCrypto.verifyDigest(input, "not-a-digest"), false)
+	assert.throws(() => abruptCrypto.verifyDigest(input, digest, "sha256", "utf16"))
+})
+
+test("crypto merkle trees", () => {
+	const { merkle } = abruptCrypto
+	const sha256 = (...parts) =>
+		crypto.createHash("sha256").update(Buffer.concat(parts)).digest()
+	const leafHash = (leaf) => sha256(Buffer.from([0]), Buffer.from(leaf))
+	const nodeHash = (left, right) => sha256(Buffer.from([1]), left, right)
+
+	const leaves = ["a", "b", "c"]
+	const expectedRoot = nodeHash(
+		nodeHash(leafHash("a"), leafHash("b")),
+		leafHash("c"),
+	).toString("hex")
+	assert.equal(merkle.root(leaves), expectedRoot)
+	assert.equal(merkle.root(leaves.map((leaf) => Buffer.from(leaf))), expectedRoot)
+	assert.equal(merkle.root(["a"]), leafHash("a").toString("hex"))
+	assert.equal(merkle.root([]), abruptCrypto.sha256(""))
+
+	for (let size = 1; size <= 9; size++) {
+		const entries = Array.from({ length: size }, (_, i) => `entry-${i}`)
+		const root = merkle.root(entries)
+		for (let index = 0; index < size; index++) {
+			const proof = merkle.proof(entries, index)
+			assert.equal(proof.size, size)
+			assert.equal(merkle.verify(root, entries[index], proof), true)
+			assert.equal(merkle.verify(root, `${entries[index]}!`, proof), false)
+			if (size > 1) {
+				const moved = { ...proof, index: (index + 1) % size }
+				assert.equal(merkle.verify(root, entries[index], moved), false)
+			}
+		}
+	}
+
+	const root = merkle.root(leaves, "sha3_256", "base64")
+	const proof = merkle.proof(leaves, 2, "sha3_256", "base64")
+	assert.equal(proof.hash, "sha3_256")
+	assert.equal(proof.encoding, "base64")
+	assert.equal(merkle.verify(root, "c", proof), true)
+	assert.throws(() => merkle.proof(leaves, 3))
+})
+
+test("crypto key derivation", () => {
+	const ikm = Buffer.from("input keying material")
+	for (const hash of ["sha1", "sha256", "sha512"]) {
+		const expected = Buffer.from(crypto.hkdfSync(hash, ikm, "salt", "tenant-42", 42))
+		const derived = abruptCrypto.hkdf(ikm, "salt", "tenant-42", 42, hash)
+		assert.equal(Buffer.isBuffer(derived), true)
+		assert.deepEqual(derived, expected)
+	}
+	assert.deepEqual(
+		abruptCrypto.hkdf(ikm, "", "", 32),
+		Buffer.from(crypto.hkdfSync("sha256", ikm, "", "", 32)),
+	)
+	assert.throws(() => abruptCrypto.hkdf(ikm, "salt", "info", 255 * 32 + 1))
+	assert.throws(() => abruptCrypto.hkdf(ikm, "salt", "info", 0))
+
+	for (const hash of ["sha1", "sha256", "sha512"]) {
+		const expected = crypto.pbkdf2Sync("password", "salt", 1000, 48, hash)
+		assert.deepEqual(abruptCrypto.pbkdf2("password", "salt", 1000, 48, hash), expected)
+	}
+	assert.deepEqual(
+		abruptCrypto.pbkdf2(Buffer.from("password"), Buffer.from("salt"), 2, 20, "sha1"),
+		Buffer.from("ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957", "hex"),
+	)
+	assert.throws(() => abruptCrypto.pbkdf2("password", "salt", 0, 32))
+	assert.throws(() => abruptCrypto.pbkdf2("password", "salt", 1, 32, "whirlpool"))
+	assert.throws(() => abruptCrypto.pbkdf2("password", "salt", 1, 0))
+	assert.throws(() => abruptCrypto.pbkdf2("password", "salt", 1, 2 ** 32 - 1), /1\.\.=1048576/)
+	assert.throws(() => abruptCrypto.pbkdf2Async("password", "salt", 1, 1024 * 1024 + 1))
+})
+
+test("crypto non-cryptographic hashes", () => {
+	const input = "hello"
+	assert.equal(abruptCrypto.xxh3(""), 0x2d06800538d394c2n)
+	assert.equal(abruptCrypto.xxh64("", 0, "hex"), "ef46db3751d8e999")
+	assert.equal(abruptCrypto.xxh64(Buffer.from(input)), abruptCrypto.xxh64(input))
+	assert.notEqual(abruptCrypto.xxh3(input, 1), abruptCrypto.xxh3(input))
+	assert.equal(
+		abruptCrypto.xxh3(input, 2n ** 64n - 1n, "hex").length,
+		16,
+	)
+	assert.throws(() => abruptCrypto.xxh3(input, -1))
+	assert.throws(() => abruptCrypto.xxh3(input, 2n ** 64n))
+
+	assert.equal(abruptCrypto.murmur3_32(input), 0x248bfa47)
+	const murmur128 = abruptCrypto.murmur3_128(input)
+	assert.equal(typeof murmur128, "bigint")
+	assert.equal(
+		abruptCrypto.murmur3_128(input, 0, "hex"),
+		Buffer.from(murmur128.toString(16).padStart(32, "0"), "hex").reverse().toString("hex"),
+	)
+	const fox = "The quick brown fox jumps over the lazy dog"
+	assert.equal(abruptCrypto.murmur3_128(fox, 0, "hex"), "6c1b07bc7bbc4be347939ac4a93c437a")
+	assert.equal(abruptCrypto.murmur3_128(fox), 0x7a433ca9c49a9347e34bbc7bbc071b6cn)
+	assert.equal(abruptCrypto.murmur3_128("", 0, "hex"), "00000000000000000000000000000000")
+
+	assert.equal(abruptCrypto.fnv1a(input), 0x4f9f2cab)
+	assert.equal(abruptCrypto.fnv1a64(input, undefined, "hex"), "a430d84680aabd0b")
+	assert.equal(abruptCrypto.crc32(input), 0x3610a686)
+	assert.equal(abruptCrypto.crc32("lo", abruptCrypto.crc32("hel")), 0x3610a686)
+	assert.equal(abruptCrypto.crc32c(input), 0x9a71bb4c)
+	assert.equal(abruptCrypto.adler32("Wikipedia"), 0x11e60398)
+	assert.equal(
+		abruptCrypto.adler32("pedia", abruptCrypto.adler32("Wiki")),
+		0x11e60398,
+	)
+})
+
+test("crypto password hashing", async () => {
+	const { password } = abruptCrypto
+	const cases = [
+		["argon2id", 1, /^\$argon2id\$v=19\$m=19456,t=1,p=1\$/],
+		["scrypt", 10, /^\$scrypt\$ln=10,r=8,p=1\$/],
+		["bcrypt", 4, /^\$2b\$04\$/],
+		["pbkdf2", 1000, /^\$pbkdf2-sha256\$i=1000,l=32\$/],
+	]
+	for (const [algorithm, cost, pattern] of cases) {
+		const pending = password.hash("correct horse", { algorithm, cost })
+		assert.equal(pending instanceof Promise, true)
+		const hashed = await pending
+		assert.match(hashed, pattern)
+		assert.equal(await password.verify("correct horse", hashed), true)
+		assert.equal(await password.verify("battery staple", hashed), false)
+		assert.equal(password.needsRehash(hashed, { algorithm, cost }), false)
+		assert.equal(password.needsRehash(hashed, { algorithm, cost: cost + 1 }), true)
+	}
+
+	const legacy = abruptCrypto.sha256("correct horse")
+	assert.equal(password.needsRehash(legacy), true)
+	await assert.rejects(password.verify("correct horse", legacy))
+	assert.throws(() => password.hash("x", { algorithm: "md5" }))
+	assert.throws(() => password.hash("x", { algorithm: "bcrypt", cost: 3 }))
+})
+
+test("crypto async variants", async () => {
+	const pending = abruptCrypto.sha256Async("abc")
+	assert.equal(pending instanceof Promise, true)
+	assert.equal(await pending, abruptCrypto.sha256("abc"))
+	assert.equal(await abruptCrypto.md5Async(Buffer.from("abc")), abruptCrypto.md5("abc"))
+	assert.equal(await abruptCrypto.sha512_256Async("abc"), abruptCrypto.sha512_256("abc"))
+	assert.equal(await abruptCrypto.keccak256Async("", "base64"), abruptCrypto.keccak256("", "base64"))
+	const buffered = await abruptCrypto.sha3_256Async("abc", "buffer")
+	assert.equal(Buffer.isBuffer(buffered), true)
+	assert.equal(buffered.toString("hex"), abruptCrypto.sha3_256("abc"))
+
+	const large = Buffer.alloc(512 * 1024, 7)
+	assert.equal(await abruptCrypto.blake3Async(large), abruptCrypto.blake3(large))
+	assert.equal(
+		await abruptCrypto.blake3Async("abc", { length: 16, context: "app v1" }),
+		abruptCrypto.blake3("abc", { length: 16, context: "app v1" }),
+	)
+
+	const vigenere = abruptCrypto.classic.vigenere.encrypt("Bring the documents to the second floor office", "KEY")
+	assert.deepEqual(await abruptCrypto.crack.vigenereAsync(vigenere), abruptCrypto.crack.vigenere(vigenere))
+	assert.throws(() => abruptCrypto.crack.vigenereAsync("abc", { maxKeyLength: 101 }), /maxKeyLength/)
+
+	const derived = await abruptCrypto.pbkdf2Async("password", "salt", 1, 20, "sha1")
+	assert.equal(derived.toString("hex"), "0c60c80f961f0e71f3a9b524af6012062fe037a6")
+
+	const [encoded, ivHex] = await abruptCrypto.AES.encodeAsync("hello", "secret")
+	assert.equal(abruptCrypto.AES.decode(encoded, ivHex, "secret"), "hello")
+	assert.equal(await abruptCrypto.AES.decodeAsync(encoded, ivHex, "secret"), "hello")
+
+	const packet = await abruptCrypto.RSA.encodeAsync("hello", "base64", 2048)
+	assert.equal(packet.bits, 2048)
+	assert.equal(packet.encoding, "base64")
+	assert.equal(abruptCrypto.RSA.decode(packet), "hello")
+	assert.equal(await abruptCrypto.RSA.decodeAsync(packet), "hello")
+
+	const controller = new AbortController()
+	const aborted = abruptCrypto.RSA.encodeAsync("hello", "hex", 2048, controller.signal)
+	controller.abort()
+	await assert.rejects(aborted)
+
+	assert.throws(() => abruptCrypto.sha256Async("abc", "latin1"))
+	assert.throws(() => abruptCrypto.RSA.encodeAsync("hello", "hex", 1000))
+	assert.throws(() => abruptCrypto.pbkdf2Async("password", "salt", 0, 20))
+})
+
 test("file namespace create/exists/read/remove", () => {
 	resetSandbox()
 
@@ -317,3 +1316,53 @@ test("file namespace enforces deterministic argument contracts", () => {
 	assert.throws(() => file.create("a", "b", "c"))
 	assert.throws(() => file.read("a", "utf8", "extra"))
 })
+
+test("crypto manifest create/verify", () => {
+	resetSandbox()
+	const { manifest } = abruptCrypto
+	const release = sandboxPath("release")
+	const appFile = sandboxPath("release", "app.js")
+	const readmeFile = sandboxPath("release", "docs", "README.md")
+	file.create(appFile.relativePath, "console.log(1)")
+	file.create(readmeFile.relativePath, "# docs")
+
+	const manifestPath = manifest.create(release.relativePath)
+	assert.equal(manifestPath, path.join(release.relativePath, "SHA256SUMS"))
+	const expected = [
+		`${crypto.createHash("sha256").update("console.log(1)").digest("hex")}  app.js`,
+		`${crypto.createHash("sha256").update("# docs").digest("hex")}  docs/README.md`,
+		"",
+	].join("\n")
+	assert.equal(file.read(manifestPath), expected)
+	assert.deepEqual(manifest.verify(manifestPath), {
+		ok: true,
+		algorithm: "sha256",
+		missing: [],
+		modified: [],
+		extra: [],
+	})
+
+	file.create(appFile.relativePath, "console.log(2)")
+	file.remove(readmeFile.relativePath)
+	file.create(sandboxPath("release", "new.txt").relativePath, "new")
+	assert.deepEqual(manifest.verify(manifestPath), {
+		ok: false,
+		algorithm: "sha256",
+		missing: ["docs/README.md"],
+		modified: ["app.js"],
+		extra: ["new.txt"],
+	})
+
+	const md5Path = manifest.create(release.relativePath, "md5")
+	assert.equal(path.basename(md5Path), "MD5SUMS")
+	assert.equal(manifest.verify(md5Path).algorithm, "md5")
+	assert.equal(manifest.verify(md5Path).ok, true)
+
+	const escapePath = sandboxPath("release", "ESCAPESUMS")
+	fs.writeFileSync(escapePath.absolutePath, `${"0".repeat(64)}  ../../../package.json\n`)
+	assert.throws(() => manifest.verify(escapePath.relativePath), /Path traversal is not allowed/)
+	fs.writeFileSync(escapePath.absolutePath, "not a manifest line\n")
+	assert.throws(() => manifest.verify(escapePath.relativePath), /line 1 is malformed/)
+	assert.throws(() => manifest.create("../outside"), /Path traversal is not allowed/)
+	assert.throws(() => manifest.create(release.relativePath, "crc"))
+})
@@ -0,0 +1,204 @@
+#![allow(dead_code)]
+use std::io::Cursor;
+
+use napi::bindgen_prelude::{BigInt, Buffer, Either};
+use napi_derive::napi;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+use xxhash_rust::xxh64::xxh64 as xxh64_with_seed;
+
+type ByteInput = Either<String, Buffer>;
+type SeedInput = Either<i64, BigInt>;
+type WideOutput = Either<BigInt, String>;
+
+const FNV32_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV32_PRIME: u32 = 0x0100_0193;
+const FNV64_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV64_PRIME: u64 = 0x0000_0100_0000_01b3;
+const ADLER32_INITIAL: u32 = 1;
+
+enum WideEncoding {
+    BigInt,
+    Hex,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn input_bytes(input: &ByteInput) -> &[u8] {
+    match input {
+        Either::A(text) => text.as_bytes(),
+        Either::B(buffer) => buffer.as_ref(),
+    }
+}
+
+#[inline(always)]
+fn normalize_seed64(seed: Option<SeedInput>) -> napi::Result<u64> {
+    match seed {
+        None => Ok(0),
+        Some(Either::A(value)) => {
+            u64::try_from(value).map_err(|_| invalid_input("Seed must be a non-negative integer"))
+        }
+        Some(Either::B(value)) => {
+            let (signed, value, lossless) = value.get_u64();
+            if signed || !lossless {
+                return Err(invalid_input("Seed must fit in an unsigned 64-bit integer"));
+            }
+            Ok(value)
+        }
+    }
+}
+
+#[inline(always)]
+fn normalize_wide_encoding(output: Option<String>) -> napi::Result<WideEncoding> {
+    let value = output.unwrap_or_else(|| "bigint".to_string());
+    let normalized = value.to_ascii_lowercase();
+    if normalized == "bigint" {
+        return Ok(WideEncoding::BigInt);
+    }
+    if normalized == "hex" {
+        return Ok(WideEncoding::Hex);
+    }
+    Err(invalid_input("Output must be one of: bigint, hex"))
+}
+
+#[inline(always)]
+fn wide_output_u64(value: u64, encoding: &WideEncoding) -> WideOutput {
+    if matches!(encoding, WideEncoding::Hex) {
+        return Either::B(format!("{value:016x}"));
+    }
+    Either::A(BigInt::from(value))
+}
+
+#[inline(always)]
+fn wide_output_u128(value: u128, encoding: &WideEncoding) -> WideOutput {
+    if matches!(encoding, WideEncoding::Hex) {
+        return Either::B(
+            value
+                .to_le_bytes()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+        );
+    }
+    Either::A(BigInt::from(value))
+}
+
+#[inline(always)]
+fn fnv1a_32(input: &[u8], seed: u32) -> u32 {
+    input.iter().fold(seed, |hash, byte| {
+        (hash ^ (*byte as u32)).wrapping_mul(FNV32_PRIME)
+    })
+}
+
+#[inline(always)]
+fn fnv1a_64(input: &[u8], seed: u64) -> u64 {
+    input.iter().fold(seed, |hash, byte| {
+        (hash ^ (*byte as u64)).wrapping_mul(FNV64_PRIME)
+    })
+}
+
+#[inline(always)]
+fn murmur3_32_bytes(input: &[u8], seed: u32) -> napi::Result<u32> {
+    murmur3::murmur3_32(&mut Cursor::new(input), seed)
+        .map_err(|error| invalid_input(&format!("MurmurHash3 failed: {error}")))
+}
+
+#[inline(always)]
+fn murmur3_128_bytes(input: &[u8], seed: u32) -> napi::Result<u128> {
+    murmur3::murmur3_x64_128(&mut Cursor::new(input), seed)
+        .map_err(|error| invalid_input(&format!("MurmurHash3 failed: {error}")))
+}
+
+#[inline(always)]
+fn crc32_bytes(input: &[u8], seed: u32) -> u32 {
+    let mut hasher = crc32fast::Hasher::new_with_initial(seed);
+    hasher.update(input);
+    hasher.finalize()
+}
+
+#[inline(always)]
+fn adler32_bytes(input: &[u8], seed: u32) -> u32 {
+    let mut hasher = adler2::Adler32::from_checksum(seed);
+    hasher.write_slice(input);
+    hasher.checksum()
+}
+
+#[napi(namespace = "crypto")]
+pub fn xxh3(
+    input: ByteInput,
+    seed: Option<SeedInput>,
+    output: Option<String>,
+) -> napi::Result<WideOutput> {
+    let seed = normalize_seed64(seed)?;
+    let encoding = normalize_wide_encoding(output)?;
+    let hash = xxh3_64_with_seed(input_bytes(&input), seed);
+    Ok(wide_output_u64(hash, &encoding))
+}
+
+#[napi(namespace = "crypto")]
+pub fn xxh64(
+    input: ByteInput,
+    seed: Option<SeedInput>,
+    output: Option<String>,
+) -> napi::Result<WideOutput> {
+    let seed = normalize_seed64(seed)?;
+    let encoding = normalize_wide_encoding(output)?;
+    let hash = xxh64_with_seed(input_bytes(&input), seed);
+    Ok(wide_output_u64(hash, &encoding))
+}
+
+#[napi(namespace = "crypto", js_name = "murmur3_32")]
+pub fn murmur3_32(input: ByteInput, seed: Option<u32>) -> napi::Result<u32> {
+    murmur3_32_bytes(input_bytes(&input), seed.unwrap_or(0))
+}
+
+#[napi(namespace = "crypto", js_name = "murmur3_128")]
+pub fn murmur3_128(
+    input: ByteInput,
+    seed: Option<u32>,
+    output: Option<String>,
+) -> napi::Result<WideOutput> {
+    let encoding = normalize_wide_encoding(output)?;
+    let hash = murmur3_128_bytes(input_bytes(&input), seed.unwrap_or(0))?;
+    Ok(wide_output_u128(hash, &encoding))
+}
+
+#[napi(namespace = "crypto", js_name = "fnv1a")]
+pub fn fnv1a(input: ByteInput, seed: Option<u32>) -> u32 {
+    fnv1a_32(input_bytes(&input), seed.unwrap_or(FNV32_OFFSET_BASIS))
+}
+
+#[napi(namespace = "crypto", js_name = "fnv1a64")]
+pub fn fnv1a64(
+    input: ByteInput,
+    seed: Option<SeedInput>,
+    output: Option<String>,
+) -> napi::Result<WideOutput> {
+    let seed = match seed {
+        Some(value) => normalize_seed64(Some(value))?,
+        None => FNV64_OFFSET_BASIS,
+    };
+    let encoding = normalize_wide_encoding(output)?;
+    Ok(wide_output_u64(
+        fnv1a_64(input_bytes(&input), seed),
+        &encoding,
+    ))
+}
+
+#[napi(namespace = "crypto")]
+pub fn crc32(input: ByteInput, seed: Option<u32>) -> u32 {
+    crc32_bytes(input_bytes(&input), seed.unwrap_or(0))
+}
+
+#[napi(namespace = "crypto", js_name = "crc32c")]
+pub fn crc32c(input: ByteInput, seed: Option<u32>) -> u32 {
+    crc32c::crc32c_append(seed.unwrap_or(0), input_bytes(&input))
+}
+
+#[napi(namespace = "crypto")]
+pub fn adler32(input: ByteInput, seed: Option<u32>) -> u32 {
+    adler32_bytes(input_bytes(&input), seed.unwrap_or(ADLER32_INITIAL))
+}
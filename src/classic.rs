@@ -0,0 +1,499 @@
+#![allow(dead_code)]
+use napi_derive::napi;
+
+use crate::crypto::rotate_ascii_char;
+
+const ALPHABET_SIZE: u8 = 26;
+const PLAYFAIR_SIZE: usize = 5;
+const PLAYFAIR_FILLER: u8 = b'X';
+const PLAYFAIR_ALTERNATE_FILLER: u8 = b'Q';
+const BACON_GROUP: usize = 5;
+const BACON_CLASSIC_SKIPPED: &[u8] = b"JV";
+
+enum BaconAlphabet {
+    Classic,
+    Full,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct BaconOptions {
+    pub alphabet: Option<String>,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn letter_index(ch: char) -> Option<u8> {
+    if ch.is_ascii_alphabetic() {
+        return Some(ch.to_ascii_lowercase() as u8 - b'a');
+    }
+    None
+}
+
+#[inline(always)]
+fn letter_from(index: u8, upper: bool) -> char {
+    let base = if upper { b'A' } else { b'a' };
+    (base + index % ALPHABET_SIZE) as char
+}
+
+#[inline(always)]
+fn normalize_letter_key(key: &str) -> napi::Result<Vec<u8>> {
+    let shifts: Vec<u8> = key.chars().filter_map(letter_index).collect();
+    if shifts.is_empty() {
+        return Err(invalid_input("Cipher key must contain at least one letter"));
+    }
+    Ok(shifts)
+}
+
+#[inline(always)]
+fn map_letters(input: &str, mut map: impl FnMut(char, u8) -> char) -> String {
+    input
+        .chars()
+        .map(|ch| match letter_index(ch) {
+            Some(index) => map(ch, index),
+            None => ch,
+        })
+        .collect()
+}
+
+#[inline(always)]
+fn vigenere_apply(input: &str, key: &str, decrypt: bool) -> napi::Result<String> {
+    let shifts = normalize_letter_key(key)?;
+    let mut position = 0;
+    Ok(map_letters(input, |ch, _| {
+        let shift = shifts[position % shifts.len()];
+        position += 1;
+        if decrypt {
+            rotate_ascii_char(ch, (ALPHABET_SIZE - shift) % ALPHABET_SIZE)
+        } else {
+            rotate_ascii_char(ch, shift)
+        }
+    }))
+}
+
+#[inline(always)]
+fn beaufort_apply(input: &str, key: &str) -> napi::Result<String> {
+    let shifts = normalize_letter_key(key)?;
+    let mut position = 0;
+    Ok(map_letters(input, |ch, index| {
+        let shift = shifts[position % shifts.len()];
+        position += 1;
+        letter_from(
+            (shift + ALPHABET_SIZE - index) % ALPHABET_SIZE,
+            ch.is_ascii_uppercase(),
+        )
+    }))
+}
+
+#[inline(always)]
+fn autokey_apply(input: &str, key: &str, decrypt: bool) -> napi::Result<String> {
+    let mut stream = normalize_letter_key(key)?;
+    let mut position = 0;
+    Ok(map_letters(input, |ch, index| {
+        let shift = stream[position];
+        position += 1;
+        if decrypt {
+            let plain = rotate_ascii_char(ch, (ALPHABET_SIZE - shift) % ALPHABET_SIZE);
+            stream.push((index + ALPHABET_SIZE - shift) % ALPHABET_SIZE);
+            plain
+        } else {
+            stream.push(index);
+            rotate_ascii_char(ch, shift)
+        }
+    }))
+}
+
+#[inline(always)]
+fn modular_inverse(value: u8) -> Option<u8> {
+    (1..ALPHABET_SIZE).find(|candidate| (value as u32 * *candidate as u32) % 26 == 1)
+}
+
+#[inline(always)]
+fn normalize_affine_key(a: u32, b: u32) -> napi::Result<(u8, u8, u8)> {
+    let a = (a % ALPHABET_SIZE as u32) as u8;
+    let inverse =
+        modular_inverse(a).ok_or_else(|| invalid_input("Affine a must be coprime with 26"))?;
+    Ok((a, (b % ALPHABET_SIZE as u32) as u8, inverse))
+}
+
+#[inline(always)]
+fn normalize_substitution_key(key: &str) -> napi::Result<[u8; 26]> {
+    let invalid = || invalid_input("Substitution key must be a permutation of the 26 letters");
+    let mut output = [0_u8; 26];
+    let mut seen = [false; 26];
+    let mut count = 0;
+    for ch in key.chars() {
+        let index = letter_index(ch).ok_or_else(invalid)?;
+        if count >= output.len() || seen[index as usize] {
+            return Err(invalid());
+        }
+        seen[index as usize] = true;
+        output[count] = index;
+        count += 1;
+    }
+    if count != output.len() {
+        return Err(invalid());
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn playfair_letter(ch: char) -> Option<u8> {
+    let upper = ch.to_ascii_uppercase();
+    if !upper.is_ascii_uppercase() {
+        return None;
+    }
+    Some(if upper == 'J' { b'I' } else { upper as u8 })
+}
+
+#[inline(always)]
+fn playfair_square(key: &str) -> Vec<u8> {
+    let mut square = Vec::with_capacity(PLAYFAIR_SIZE * PLAYFAIR_SIZE);
+    for letter in key
+        .chars()
+        .filter_map(playfair_letter)
+        .chain((b'A'..=b'Z').map(char::from).filter_map(playfair_letter))
+    {
+        if !square.contains(&letter) {
+            square.push(letter);
+        }
+    }
+    square
+}
+
+#[inline(always)]
+fn playfair_digraphs(input: &str) -> Vec<(u8, u8)> {
+    let letters: Vec<u8> = input.chars().filter_map(playfair_letter).collect();
+    let filler_for = |letter: u8| {
+        if letter == PLAYFAIR_FILLER {
+            PLAYFAIR_ALTERNATE_FILLER
+        } else {
+            PLAYFAIR_FILLER
+        }
+    };
+    let mut output = Vec::with_capacity(letters.len() / 2 + 1);
+    let mut index = 0;
+    while index < letters.len() {
+        let first = letters[index];
+        match letters.get(index + 1) {
+            Some(second) if *second != first => {
+                output.push((first, *second));
+                index += 2;
+            }
+            _ => {
+                output.push((first, filler_for(first)));
+                index += 1;
+            }
+        }
+    }
+    output
+}
+
+#[inline(always)]
+fn playfair_apply(input: &str, key: &str, decrypt: bool) -> napi::Result<String> {
+    let square = playfair_square(key);
+    let position = |letter: u8| {
+        let index = square
+            .iter()
+            .position(|value| *value == letter)
+            .unwrap_or(0);
+        (index / PLAYFAIR_SIZE, index % PLAYFAIR_SIZE)
+    };
+    let at = |row: usize, column: usize| square[row * PLAYFAIR_SIZE + column] as char;
+    let step = if decrypt { PLAYFAIR_SIZE - 1 } else { 1 };
+
+    let pairs = if decrypt {
+        let letters: Vec<u8> = input.chars().filter_map(playfair_letter).collect();
+        if !letters.len().is_multiple_of(2) {
+            return Err(invalid_input(
+                "Playfair ciphertext must have an even number of letters",
+            ));
+        }
+        letters.chunks(2).map(|pair| (pair[0], pair[1])).collect()
+    } else {
+        playfair_digraphs(input)
+    };
+
+    let mut output = String::with_capacity(pairs.len() * 2);
+    for (first, second) in pairs {
+        let (row_a, column_a) = position(first);
+        let (row_b, column_b) = position(second);
+        if row_a == row_b {
+            output.push(at(row_a, (column_a + step) % PLAYFAIR_SIZE));
+            output.push(at(row_b, (column_b + step) % PLAYFAIR_SIZE));
+        } else if column_a == column_b {
+            output.push(at((row_a + step) % PLAYFAIR_SIZE, column_a));
+            output.push(at((row_b + step) % PLAYFAIR_SIZE, column_b));
+        } else {
+            output.push(at(row_a, column_b));
+            output.push(at(row_b, column_a));
+        }
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn rail_pattern(length: usize, rails: u32, offset: u32) -> napi::Result<Vec<usize>> {
+    if rails < 2 {
+        return Err(invalid_input("Rail fence needs at least 2 rails"));
+    }
+    let rails = rails as usize;
+    let cycle = 2 * (rails - 1);
+    Ok((0..length)
+        .map(|index| {
+            let phase = (index + offset as usize) % cycle;
+            if phase < rails { phase } else { cycle - phase }
+        })
+        .collect())
+}
+
+#[inline(always)]
+fn rail_order(pattern: &[usize]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..pattern.len()).collect();
+    order.sort_by_key(|index| pattern[*index]);
+    order
+}
+
+#[inline(always)]
+fn columnar_order(key: &str) -> napi::Result<Vec<usize>> {
+    let key: Vec<char> = key.chars().map(|ch| ch.to_ascii_lowercase()).collect();
+    if key.is_empty() {
+        return Err(invalid_input("Columnar key must not be empty"));
+    }
+    let mut order: Vec<usize> = (0..key.len()).collect();
+    order.sort_by_key(|index| key[*index]);
+    Ok(order)
+}
+
+#[inline(always)]
+fn columnar_positions(length: usize, key: &str) -> napi::Result<Vec<usize>> {
+    let order = columnar_order(key)?;
+    let columns = order.len();
+    let mut positions = Vec::with_capacity(length);
+    for column in order {
+        positions.extend((column..length).step_by(columns));
+    }
+    Ok(positions)
+}
+
+#[inline(always)]
+fn normalize_bacon_alphabet(options: Option<BaconOptions>) -> napi::Result<BaconAlphabet> {
+    match options.unwrap_or_default().alphabet.as_deref() {
+        None | Some("classic") => Ok(BaconAlphabet::Classic),
+        Some("full") => Ok(BaconAlphabet::Full),
+        _ => Err(invalid_input(
+            "Bacon alphabet must be one of: classic, full",
+        )),
+    }
+}
+
+#[inline(always)]
+fn bacon_letters(alphabet: &BaconAlphabet) -> Vec<u8> {
+    match alphabet {
+        BaconAlphabet::Full => (b'A'..=b'Z').collect(),
+        BaconAlphabet::Classic => (b'A'..=b'Z')
+            .filter(|letter| !BACON_CLASSIC_SKIPPED.contains(letter))
+            .collect(),
+    }
+}
+
+#[inline(always)]
+fn bacon_index(letter: u8, alphabet: &BaconAlphabet) -> usize {
+    let letter = match (alphabet, letter) {
+        (BaconAlphabet::Classic, b'J') => b'I',
+        (BaconAlphabet::Classic, b'V') => b'U',
+        _ => letter,
+    };
+    bacon_letters(alphabet)
+        .iter()
+        .position(|value| *value == letter)
+        .unwrap_or(0)
+}
+
+#[napi(namespace = "vigenere", js_name = "encrypt")]
+pub fn vigenere_encrypt(input: String, key: String) -> napi::Result<String> {
+    vigenere_apply(&input, &key, false)
+}
+
+#[napi(namespace = "vigenere", js_name = "decrypt")]
+pub fn vigenere_decrypt(input: String, key: String) -> napi::Result<String> {
+    vigenere_apply(&input, &key, true)
+}
+
+#[napi(namespace = "beaufort", js_name = "encrypt")]
+pub fn beaufort_encrypt(input: String, key: String) -> napi::Result<String> {
+    beaufort_apply(&input, &key)
+}
+
+#[napi(namespace = "beaufort", js_name = "decrypt")]
+pub fn beaufort_decrypt(input: String, key: String) -> napi::Result<String> {
+    beaufort_apply(&input, &key)
+}
+
+#[napi(namespace = "autokey", js_name = "encrypt")]
+pub fn autokey_encrypt(input: String, key: String) -> napi::Result<String> {
+    autokey_apply(&input, &key, false)
+}
+
+#[napi(namespace = "autokey", js_name = "decrypt")]
+pub fn autokey_decrypt(input: String, key: String) -> napi::Result<String> {
+    autokey_apply(&input, &key, true)
+}
+
+#[napi(namespace = "atbash", js_name = "encrypt")]
+pub fn atbash_encrypt(input: String) -> String {
+    map_letters(&input, |ch, index| {
+        letter_from(ALPHABET_SIZE - 1 - index, ch.is_ascii_uppercase())
+    })
+}
+
+#[napi(namespace = "atbash", js_name = "decrypt")]
+pub fn atbash_decrypt(input: String) -> String {
+    atbash_encrypt(input)
+}
+
+#[napi(namespace = "affine", js_name = "encrypt")]
+pub fn affine_encrypt(input: String, a: u32, b: u32) -> napi::Result<String> {
+    let (a, b, _) = normalize_affine_key(a, b)?;
+    Ok(map_letters(&input, |ch, index| {
+        let mapped = a as u32 * index as u32 + b as u32;
+        letter_from(
+            (mapped % ALPHABET_SIZE as u32) as u8,
+            ch.is_ascii_uppercase(),
+        )
+    }))
+}
+
+#[napi(namespace = "affine", js_name = "decrypt")]
+pub fn affine_decrypt(input: String, a: u32, b: u32) -> napi::Result<String> {
+    let (_, b, inverse) = normalize_affine_key(a, b)?;
+    Ok(map_letters(&input, |ch, index| {
+        let shifted = (index + ALPHABET_SIZE - b) as u32 * inverse as u32;
+        letter_from(
+            (shifted % ALPHABET_SIZE as u32) as u8,
+            ch.is_ascii_uppercase(),
+        )
+    }))
+}
+
+#[napi(namespace = "substitution", js_name = "encrypt")]
+pub fn substitution_encrypt(input: String, key: String) -> napi::Result<String> {
+    let table = normalize_substitution_key(&key)?;
+    Ok(map_letters(&input, |ch, index| {
+        letter_from(table[index as usize], ch.is_ascii_uppercase())
+    }))
+}
+
+#[napi(namespace = "substitution", js_name = "decrypt")]
+pub fn substitution_decrypt(input: String, key: String) -> napi::Result<String> {
+    let table = normalize_substitution_key(&key)?;
+    let mut inverse = [0_u8; 26];
+    for (plain, cipher) in table.iter().enumerate() {
+        inverse[*cipher as usize] = plain as u8;
+    }
+    Ok(map_letters(&input, |ch, index| {
+        letter_from(inverse[index as usize], ch.is_ascii_uppercase())
+    }))
+}
+
+#[napi(namespace = "playfair", js_name = "encrypt")]
+pub fn playfair_encrypt(input: String, key: String) -> napi::Result<String> {
+    playfair_apply(&input, &key, false)
+}
+
+#[napi(namespace = "playfair", js_name = "decrypt")]
+pub fn playfair_decrypt(input: String, key: String) -> napi::Result<String> {
+    playfair_apply(&input, &key, true)
+}
+
+#[napi(namespace = "railFence", js_name = "encrypt")]
+pub fn rail_fence_encrypt(input: String, rails: u32, offset: Option<u32>) -> napi::Result<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let pattern = rail_pattern(chars.len(), rails, offset.unwrap_or(0))?;
+    Ok(rail_order(&pattern)
+        .into_iter()
+        .map(|index| chars[index])
+        .collect())
+}
+
+#[napi(namespace = "railFence", js_name = "decrypt")]
+pub fn rail_fence_decrypt(input: String, rails: u32, offset: Option<u32>) -> napi::Result<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let pattern = rail_pattern(chars.len(), rails, offset.unwrap_or(0))?;
+    let mut output = vec![' '; chars.len()];
+    for (ch, index) in chars.into_iter().zip(rail_order(&pattern)) {
+        output[index] = ch;
+    }
+    Ok(output.into_iter().collect())
+}
+
+#[napi(namespace = "columnar", js_name = "encrypt")]
+pub fn columnar_encrypt(input: String, key: String) -> napi::Result<String> {
+    let chars: Vec<char> = input.chars().collect();
+    Ok(columnar_positions(chars.len(), &key)?
+        .into_iter()
+        .map(|index| chars[index])
+        .collect())
+}
+
+#[napi(namespace = "columnar", js_name = "decrypt")]
+pub fn columnar_decrypt(input: String, key: String) -> napi::Result<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = vec![' '; chars.len()];
+    for (ch, index) in chars
+        .into_iter()
+        .zip(columnar_positions(output.len(), &key)?)
+    {
+        output[index] = ch;
+    }
+    Ok(output.into_iter().collect())
+}
+
+#[napi(namespace = "bacon", js_name = "encrypt")]
+pub fn bacon_encrypt(input: String, options: Option<BaconOptions>) -> napi::Result<String> {
+    let alphabet = normalize_bacon_alphabet(options)?;
+    let groups: Vec<String> = input
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|ch| {
+            let index = bacon_index(ch.to_ascii_uppercase() as u8, &alphabet);
+            (0..BACON_GROUP)
+                .rev()
+                .map(|bit| if index >> bit & 1 == 0 { 'A' } else { 'B' })
+                .collect()
+        })
+        .collect();
+    Ok(groups.join(" "))
+}
+
+#[napi(namespace = "bacon", js_name = "decrypt")]
+pub fn bacon_decrypt(input: String, options: Option<BaconOptions>) -> napi::Result<String> {
+    let alphabet = normalize_bacon_alphabet(options)?;
+    let letters = bacon_letters(&alphabet);
+    let bits: Vec<usize> = input
+        .chars()
+        .filter_map(|ch| match ch.to_ascii_uppercase() {
+            'A' => Some(0),
+            'B' => Some(1),
+            _ => None,
+        })
+        .collect();
+    if !bits.len().is_multiple_of(BACON_GROUP) {
+        return Err(invalid_input(
+            "Bacon ciphertext must contain groups of 5 A/B letters",
+        ));
+    }
+    bits.chunks(BACON_GROUP)
+        .map(|group| {
+            let index = group.iter().fold(0, |value, bit| value << 1 | bit);
+            letters
+                .get(index)
+                .map(|letter| *letter as char)
+                .ok_or_else(|| invalid_input("Bacon group is outside the alphabet"))
+        })
+        .collect()
+}
@@ -0,0 +1,1542 @@
+#![allow(dead_code)]
+use base64::Engine as _;
+use napi::bindgen_prelude::{BigInt, Buffer, Either, Either3};
+use napi_derive::napi;
+use sha2::{Digest, Sha256};
+
+type ByteInput = Either<String, Buffer>;
+type IntegerInput = Either3<String, Buffer, BigInt>;
+
+const BASE58_BITCOIN_ALPHABET: &[u8; 58] =
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE58_FLICKR_ALPHABET: &[u8; 58] =
+    b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+const BASE36_ALPHABET: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE62_ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE_N_MAX_RADIX: usize = 256;
+const BASE58CHECK_CHECKSUM_BYTES: usize = 4;
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_SEPARATOR: char = '1';
+const BECH32_CHECKSUM_CHARS: usize = 6;
+const BECH32_MAX_LENGTH: usize = 90;
+const BECH32_MAX_HRP_LENGTH: usize = 83;
+const BECH32_MAX_WITNESS_VERSION: u32 = 16;
+const BECH32_MIN_PROGRAM_BYTES: usize = 2;
+const BECH32_MAX_PROGRAM_BYTES: usize = 40;
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+const BECH32_GENERATOR: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+const RFC1924_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+const ASCII85_OFFSET: u8 = b'!';
+const ASCII85_ZERO_GROUP: char = 'z';
+const ASCII85_PREFIX: &str = "<~";
+const ASCII85_SUFFIX: &str = "~>";
+const BASE85_GROUP_BYTES: usize = 4;
+const BASE85_GROUP_CHARS: usize = 5;
+
+const PERCENT_PATH_SAFE: &[u8] = b"!$&'()*+,;=:@/";
+const QP_LINE_CHARS: usize = 76;
+const ENCODED_WORD_MAX_CHARS: usize = 75;
+const ENCODED_WORD_CHARSET: &str = "UTF-8";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+const HTML_ENTITIES: &[(&str, char)] = &[
+    ("amp", '\u{0026}'),
+    ("lt", '\u{003c}'),
+    ("gt", '\u{003e}'),
+    ("quot", '\u{0022}'),
+    ("apos", '\u{0027}'),
+    ("nbsp", '\u{00a0}'),
+    ("iexcl", '\u{00a1}'),
+    ("cent", '\u{00a2}'),
+    ("pound", '\u{00a3}'),
+    ("curren", '\u{00a4}'),
+    ("yen", '\u{00a5}'),
+    ("brvbar", '\u{00a6}'),
+    ("sect", '\u{00a7}'),
+    ("uml", '\u{00a8}'),
+    ("copy", '\u{00a9}'),
+    ("ordf", '\u{00aa}'),
+    ("laquo", '\u{00ab}'),
+    ("not", '\u{00ac}'),
+    ("shy", '\u{00ad}'),
+    ("reg", '\u{00ae}'),
+    ("macr", '\u{00af}'),
+    ("deg", '\u{00b0}'),
+    ("plusmn", '\u{00b1}'),
+    ("sup2", '\u{00b2}'),
+    ("sup3", '\u{00b3}'),
+    ("acute", '\u{00b4}'),
+    ("micro", '\u{00b5}'),
+    ("para", '\u{00b6}'),
+    ("middot", '\u{00b7}'),
+    ("cedil", '\u{00b8}'),
+    ("sup1", '\u{00b9}'),
+    ("ordm", '\u{00ba}'),
+    ("raquo", '\u{00bb}'),
+    ("frac14", '\u{00bc}'),
+    ("frac12", '\u{00bd}'),
+    ("frac34", '\u{00be}'),
+    ("iquest", '\u{00bf}'),
+    ("Agrave", '\u{00c0}'),
+    ("Aacute", '\u{00c1}'),
+    ("Acirc", '\u{00c2}'),
+    ("Atilde", '\u{00c3}'),
+    ("Auml", '\u{00c4}'),
+    ("Aring", '\u{00c5}'),
+    ("AElig", '\u{00c6}'),
+    ("Ccedil", '\u{00c7}'),
+    ("Egrave", '\u{00c8}'),
+    ("Eacute", '\u{00c9}'),
+    ("Ecirc", '\u{00ca}'),
+    ("Euml", '\u{00cb}'),
+    ("Igrave", '\u{00cc}'),
+    ("Iacute", '\u{00cd}'),
+    ("Icirc", '\u{00ce}'),
+    ("Iuml", '\u{00cf}'),
+    ("ETH", '\u{00d0}'),
+    ("Ntilde", '\u{00d1}'),
+    ("Ograve", '\u{00d2}'),
+    ("Oacute", '\u{00d3}'),
+    ("Ocirc", '\u{00d4}'),
+    ("Otilde", '\u{00d5}'),
+    ("Ouml", '\u{00d6}'),
+    ("times", '\u{00d7}'),
+    ("Oslash", '\u{00d8}'),
+    ("Ugrave", '\u{00d9}'),
+    ("Uacute", '\u{00da}'),
+    ("Ucirc", '\u{00db}'),
+    ("Uuml", '\u{00dc}'),
+    ("Yacute", '\u{00dd}'),
+    ("THORN", '\u{00de}'),
+    ("szlig", '\u{00df}'),
+    ("agrave", '\u{00e0}'),
+    ("aacute", '\u{00e1}'),
+    ("acirc", '\u{00e2}'),
+    ("atilde", '\u{00e3}'),
+    ("auml", '\u{00e4}'),
+    ("aring", '\u{00e5}'),
+    ("aelig", '\u{00e6}'),
+    ("ccedil", '\u{00e7}'),
+    ("egrave", '\u{00e8}'),
+    ("eacute", '\u{00e9}'),
+    ("ecirc", '\u{00ea}'),
+    ("euml", '\u{00eb}'),
+    ("igrave", '\u{00ec}'),
+    ("iacute", '\u{00ed}'),
+    ("icirc", '\u{00ee}'),
+    ("iuml", '\u{00ef}'),
+    ("eth", '\u{00f0}'),
+    ("ntilde", '\u{00f1}'),
+    ("ograve", '\u{00f2}'),
+    ("oacute", '\u{00f3}'),
+    ("ocirc", '\u{00f4}'),
+    ("otilde", '\u{00f5}'),
+    ("ouml", '\u{00f6}'),
+    ("divide", '\u{00f7}'),
+    ("oslash", '\u{00f8}'),
+    ("ugrave", '\u{00f9}'),
+    ("uacute", '\u{00fa}'),
+    ("ucirc", '\u{00fb}'),
+    ("uuml", '\u{00fc}'),
+    ("yacute", '\u{00fd}'),
+    ("thorn", '\u{00fe}'),
+    ("yuml", '\u{00ff}'),
+    ("OElig", '\u{0152}'),
+    ("oelig", '\u{0153}'),
+    ("Scaron", '\u{0160}'),
+    ("scaron", '\u{0161}'),
+    ("Yuml", '\u{0178}'),
+    ("fnof", '\u{0192}'),
+    ("circ", '\u{02c6}'),
+    ("tilde", '\u{02dc}'),
+    ("ensp", '\u{2002}'),
+    ("emsp", '\u{2003}'),
+    ("thinsp", '\u{2009}'),
+    ("zwnj", '\u{200c}'),
+    ("zwj", '\u{200d}'),
+    ("lrm", '\u{200e}'),
+    ("rlm", '\u{200f}'),
+    ("ndash", '\u{2013}'),
+    ("mdash", '\u{2014}'),
+    ("lsquo", '\u{2018}'),
+    ("rsquo", '\u{2019}'),
+    ("sbquo", '\u{201a}'),
+    ("ldquo", '\u{201c}'),
+    ("rdquo", '\u{201d}'),
+    ("bdquo", '\u{201e}'),
+    ("dagger", '\u{2020}'),
+    ("Dagger", '\u{2021}'),
+    ("bull", '\u{2022}'),
+    ("hellip", '\u{2026}'),
+    ("permil", '\u{2030}'),
+    ("prime", '\u{2032}'),
+    ("Prime", '\u{2033}'),
+    ("lsaquo", '\u{2039}'),
+    ("rsaquo", '\u{203a}'),
+    ("euro", '\u{20ac}'),
+    ("trade", '\u{2122}'),
+    ("larr", '\u{2190}'),
+    ("uarr", '\u{2191}'),
+    ("rarr", '\u{2192}'),
+    ("darr", '\u{2193}'),
+    ("harr", '\u{2194}'),
+    ("minus", '\u{2212}'),
+    ("infin", '\u{221e}'),
+    ("ne", '\u{2260}'),
+    ("le", '\u{2264}'),
+    ("ge", '\u{2265}'),
+    ("asymp", '\u{2248}'),
+    ("spades", '\u{2660}'),
+    ("clubs", '\u{2663}'),
+    ("hearts", '\u{2665}'),
+    ("diams", '\u{2666}'),
+];
+
+enum PercentMode {
+    Component,
+    Path,
+}
+
+enum HtmlEntityMode {
+    Named,
+    Numeric,
+}
+
+enum EncodedWordEncoding {
+    Base64,
+    Quoted,
+}
+
+enum Base85Variant {
+    Ascii85,
+    Z85,
+    Rfc1924,
+}
+
+enum Bech32Variant {
+    Bech32,
+    Bech32m,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Base58Options {
+    pub alphabet: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Base85Options {
+    pub variant: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct PercentOptions {
+    pub mode: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct HtmlOptions {
+    pub mode: Option<String>,
+    pub ascii: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct QuotedPrintableOptions {
+    pub binary: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct EncodedWordOptions {
+    pub encoding: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Bech32EncodeOptions {
+    pub version: Option<u32>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Bech32DecodeOptions {
+    pub witness: Option<bool>,
+}
+
+#[napi(object)]
+pub struct Bech32Decoded {
+    pub hrp: String,
+    pub version: Option<u32>,
+    pub data: Buffer,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn input_bytes(input: &ByteInput) -> &[u8] {
+    match input {
+        Either::A(text) => text.as_bytes(),
+        Either::B(buffer) => buffer.as_ref(),
+    }
+}
+
+#[inline(always)]
+fn input_text(input: &ByteInput) -> napi::Result<&str> {
+    match input {
+        Either::A(text) => Ok(text),
+        Either::B(buffer) => std::str::from_utf8(buffer.as_ref())
+            .map_err(|_| invalid_input("Encoded input must be valid UTF-8 text")),
+    }
+}
+
+#[inline(always)]
+fn decoded_text(decoded: Vec<u8>) -> napi::Result<String> {
+    String::from_utf8(decoded)
+        .map_err(|error| invalid_input(&format!("Decoded bytes are not valid UTF-8: {error}")))
+}
+
+#[inline(always)]
+fn normalize_base58_alphabet(options: Option<Base58Options>) -> napi::Result<&'static [u8; 58]> {
+    let Some(value) = options.unwrap_or_default().alphabet else {
+        return Ok(BASE58_BITCOIN_ALPHABET);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "bitcoin" => Ok(BASE58_BITCOIN_ALPHABET),
+        "flickr" => Ok(BASE58_FLICKR_ALPHABET),
+        _ => Err(invalid_input(
+            "Base58 alphabet must be one of: bitcoin, flickr",
+        )),
+    }
+}
+
+#[inline(always)]
+fn radix_digits(input: &[u8], radix: u32) -> Vec<u32> {
+    let mut digits: Vec<u32> = Vec::with_capacity(input.len() * 2 + 1);
+    for byte in input {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += *digit << 8;
+            *digit = carry % radix;
+            carry /= radix;
+        }
+        while carry > 0 {
+            digits.push(carry % radix);
+            carry /= radix;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+#[inline(always)]
+fn radix_bytes(digits: &[u32], radix: u32) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(digits.len() + 1);
+    for value in digits {
+        let mut carry = *value;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * radix;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.reverse();
+    bytes
+}
+
+#[inline(always)]
+fn radix_encode(input: &[u8], alphabet: &[char]) -> String {
+    let zeros = input.iter().take_while(|byte| **byte == 0).count();
+    let digits = radix_digits(&input[zeros..], alphabet.len() as u32);
+    let mut output = String::with_capacity(zeros + digits.len());
+    output.extend(std::iter::repeat_n(alphabet[0], zeros));
+    output.extend(digits.iter().map(|digit| alphabet[*digit as usize]));
+    output
+}
+
+#[inline(always)]
+fn radix_decode(input: &str, alphabet: &[char], name: &str) -> napi::Result<Vec<u8>> {
+    let zeros = input.chars().take_while(|ch| *ch == alphabet[0]).count();
+    let mut digits = Vec::with_capacity(input.len());
+    for ch in input.chars().skip(zeros) {
+        let Some(value) = alphabet.iter().position(|candidate| *candidate == ch) else {
+            return Err(invalid_input(&format!(
+                "Invalid {name} input: unexpected character {ch:?}"
+            )));
+        };
+        digits.push(value as u32);
+    }
+
+    let mut output = vec![0_u8; zeros];
+    output.extend(radix_bytes(&digits, alphabet.len() as u32));
+    Ok(output)
+}
+
+#[inline(always)]
+fn base58_chars(alphabet: &[u8; 58]) -> Vec<char> {
+    alphabet.iter().map(|byte| *byte as char).collect()
+}
+
+#[inline(always)]
+fn base58_encode_bytes(input: &[u8], alphabet: &[u8; 58]) -> String {
+    radix_encode(input, &base58_chars(alphabet))
+}
+
+#[inline(always)]
+fn base58_decode_bytes(input: &str, alphabet: &[u8; 58]) -> napi::Result<Vec<u8>> {
+    radix_decode(input, &base58_chars(alphabet), "base58")
+}
+
+#[inline(always)]
+fn normalize_base_n_alphabet(alphabet: &str) -> napi::Result<Vec<char>> {
+    let chars: Vec<char> = match alphabet.to_ascii_lowercase().as_str() {
+        "base36" => BASE36_ALPHABET.chars().collect(),
+        "base62" => BASE62_ALPHABET.chars().collect(),
+        _ => alphabet.chars().collect(),
+    };
+    if chars.len() < 2 || chars.len() > BASE_N_MAX_RADIX {
+        return Err(invalid_input(
+            "Alphabet must contain between 2 and 256 characters",
+        ));
+    }
+    for (index, ch) in chars.iter().enumerate() {
+        if chars[..index].contains(ch) {
+            return Err(invalid_input(&format!(
+                "Alphabet must not contain duplicate characters: {ch:?}"
+            )));
+        }
+    }
+    Ok(chars)
+}
+
+#[inline(always)]
+fn bigint_to_bytes(value: &BigInt) -> napi::Result<Vec<u8>> {
+    if value.sign_bit && value.words.iter().any(|word| *word != 0) {
+        return Err(invalid_input("BigInt input must not be negative"));
+    }
+    let bytes: Vec<u8> = value
+        .words
+        .iter()
+        .rev()
+        .flat_map(|word| word.to_be_bytes())
+        .skip_while(|byte| *byte == 0)
+        .collect();
+    Ok(bytes)
+}
+
+#[inline(always)]
+fn bytes_to_bigint(bytes: &[u8]) -> BigInt {
+    let mut words = Vec::with_capacity(bytes.len() / 8 + 1);
+    for chunk in bytes.rchunks(8) {
+        let mut word = [0_u8; 8];
+        word[8 - chunk.len()..].copy_from_slice(chunk);
+        words.push(u64::from_be_bytes(word));
+    }
+    if words.is_empty() {
+        words.push(0);
+    }
+    BigInt {
+        sign_bit: false,
+        words,
+    }
+}
+
+#[inline(always)]
+fn base_n_encode_input(input: &IntegerInput, alphabet: &[char]) -> napi::Result<String> {
+    match input {
+        Either3::A(text) => Ok(radix_encode(text.as_bytes(), alphabet)),
+        Either3::B(buffer) => Ok(radix_encode(buffer.as_ref(), alphabet)),
+        Either3::C(value) => {
+            let bytes = bigint_to_bytes(value)?;
+            if bytes.is_empty() {
+                return Ok(alphabet[0].to_string());
+            }
+            Ok(radix_encode(&bytes, alphabet))
+        }
+    }
+}
+
+#[inline(always)]
+fn base58check_checksum(payload: &[u8]) -> [u8; BASE58CHECK_CHECKSUM_BYTES] {
+    let digest = Sha256::digest(Sha256::digest(payload));
+    let mut checksum = [0_u8; BASE58CHECK_CHECKSUM_BYTES];
+    checksum.copy_from_slice(&digest[..BASE58CHECK_CHECKSUM_BYTES]);
+    checksum
+}
+
+#[inline(always)]
+fn base58check_encode_bytes(payload: &[u8], alphabet: &[u8; 58]) -> String {
+    let mut data = payload.to_vec();
+    data.extend_from_slice(&base58check_checksum(payload));
+    base58_encode_bytes(&data, alphabet)
+}
+
+#[inline(always)]
+fn base58check_decode_bytes(input: &str, alphabet: &[u8; 58]) -> napi::Result<Vec<u8>> {
+    let mut data = base58_decode_bytes(input, alphabet)?;
+    if data.len() < BASE58CHECK_CHECKSUM_BYTES {
+        return Err(invalid_input("Invalid base58check input: too short"));
+    }
+
+    let checksum = data.split_off(data.len() - BASE58CHECK_CHECKSUM_BYTES);
+    if checksum != base58check_checksum(&data) {
+        return Err(invalid_input(
+            "Invalid base58check input: checksum mismatch",
+        ));
+    }
+    Ok(data)
+}
+
+#[inline(always)]
+fn normalize_base85_variant(options: Option<Base85Options>) -> napi::Result<Base85Variant> {
+    let Some(value) = options.unwrap_or_default().variant else {
+        return Ok(Base85Variant::Ascii85);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "ascii85" => Ok(Base85Variant::Ascii85),
+        "z85" => Ok(Base85Variant::Z85),
+        "rfc1924" => Ok(Base85Variant::Rfc1924),
+        _ => Err(invalid_input(
+            "Base85 variant must be one of: ascii85, z85, rfc1924",
+        )),
+    }
+}
+
+#[inline(always)]
+fn base85_char(value: u32, variant: &Base85Variant) -> char {
+    match variant {
+        Base85Variant::Ascii85 => (value as u8 + ASCII85_OFFSET) as char,
+        Base85Variant::Z85 => Z85_ALPHABET[value as usize] as char,
+        Base85Variant::Rfc1924 => RFC1924_ALPHABET[value as usize] as char,
+    }
+}
+
+#[inline(always)]
+fn base85_value(ch: u8, variant: &Base85Variant) -> Option<u32> {
+    match variant {
+        Base85Variant::Ascii85 => (ASCII85_OFFSET..ASCII85_OFFSET + 85)
+            .contains(&ch)
+            .then(|| (ch - ASCII85_OFFSET) as u32),
+        Base85Variant::Z85 => Z85_ALPHABET
+            .iter()
+            .position(|value| *value == ch)
+            .map(|index| index as u32),
+        Base85Variant::Rfc1924 => RFC1924_ALPHABET
+            .iter()
+            .position(|value| *value == ch)
+            .map(|index| index as u32),
+    }
+}
+
+#[inline(always)]
+fn base85_encode_bytes(input: &[u8], variant: &Base85Variant) -> napi::Result<String> {
+    if matches!(variant, Base85Variant::Z85) && !input.len().is_multiple_of(BASE85_GROUP_BYTES) {
+        return Err(invalid_input(
+            "Z85 input length must be a multiple of 4 bytes",
+        ));
+    }
+
+    let mut output = String::with_capacity(input.len() * 5 / 4 + 5);
+    if matches!(variant, Base85Variant::Ascii85) {
+        output.push_str(ASCII85_PREFIX);
+    }
+    for chunk in input.chunks(BASE85_GROUP_BYTES) {
+        if matches!(variant, Base85Variant::Ascii85)
+            && chunk.len() == BASE85_GROUP_BYTES
+            && chunk.iter().all(|byte| *byte == 0)
+        {
+            output.push(ASCII85_ZERO_GROUP);
+            continue;
+        }
+
+        let mut group = [0_u8; BASE85_GROUP_BYTES];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(group);
+        let mut digits = [' '; BASE85_GROUP_CHARS];
+        for digit in digits.iter_mut().rev() {
+            *digit = base85_char(value % 85, variant);
+            value /= 85;
+        }
+        output.extend(&digits[..chunk.len() + 1]);
+    }
+    if matches!(variant, Base85Variant::Ascii85) {
+        output.push_str(ASCII85_SUFFIX);
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn base85_decode_group(digits: &[u32], variant: &Base85Variant) -> napi::Result<[u8; 4]> {
+    let mut value = 0_u64;
+    for index in 0..BASE85_GROUP_CHARS {
+        value = value * 85 + digits.get(index).copied().unwrap_or(84) as u64;
+    }
+    let value = u32::try_from(value).map_err(|_| {
+        invalid_input(&format!(
+            "Invalid {} input: group value overflows 32 bits",
+            base85_variant_name(variant)
+        ))
+    })?;
+    Ok(value.to_be_bytes())
+}
+
+#[inline(always)]
+fn base85_variant_name(variant: &Base85Variant) -> &'static str {
+    match variant {
+        Base85Variant::Ascii85 => "ascii85",
+        Base85Variant::Z85 => "z85",
+        Base85Variant::Rfc1924 => "rfc1924",
+    }
+}
+
+#[inline(always)]
+fn strip_ascii85_framing(input: &str) -> &str {
+    let trimmed = input.trim();
+    let trimmed = trimmed.strip_prefix(ASCII85_PREFIX).unwrap_or(trimmed);
+    trimmed.strip_suffix(ASCII85_SUFFIX).unwrap_or(trimmed)
+}
+
+#[inline(always)]
+fn base85_decode_bytes(input: &str, variant: &Base85Variant) -> napi::Result<Vec<u8>> {
+    let name = base85_variant_name(variant);
+    let body = match variant {
+        Base85Variant::Ascii85 => strip_ascii85_framing(input),
+        Base85Variant::Z85 | Base85Variant::Rfc1924 => input,
+    };
+    if matches!(variant, Base85Variant::Z85) && !body.len().is_multiple_of(BASE85_GROUP_CHARS) {
+        return Err(invalid_input(
+            "Invalid z85 input: length must be a multiple of 5 characters",
+        ));
+    }
+
+    let mut output = Vec::with_capacity(body.len() * 4 / 5 + 4);
+    let mut digits = Vec::with_capacity(BASE85_GROUP_CHARS);
+    for ch in body.bytes() {
+        if matches!(variant, Base85Variant::Ascii85) {
+            if ch.is_ascii_whitespace() {
+                continue;
+            }
+            if ch == ASCII85_ZERO_GROUP as u8 {
+                if !digits.is_empty() {
+                    return Err(invalid_input("Invalid ascii85 input: 'z' inside a group"));
+                }
+                output.extend_from_slice(&[0; BASE85_GROUP_BYTES]);
+                continue;
+            }
+        }
+
+        let Some(value) = base85_value(ch, variant) else {
+            return Err(invalid_input(&format!(
+                "Invalid {name} input: unexpected character {:?}",
+                ch as char
+            )));
+        };
+        digits.push(value);
+        if digits.len() == BASE85_GROUP_CHARS {
+            output.extend_from_slice(&base85_decode_group(&digits, variant)?);
+            digits.clear();
+        }
+    }
+
+    if digits.len() == 1 {
+        return Err(invalid_input(&format!(
+            "Invalid {name} input: final group is too short"
+        )));
+    }
+    if !digits.is_empty() {
+        let group = base85_decode_group(&digits, variant)?;
+        output.extend_from_slice(&group[..digits.len() - 1]);
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn hex_value(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|value| value as u8)
+}
+
+#[inline(always)]
+fn push_hex_escape(output: &mut String, prefix: char, byte: u8) {
+    output.push(prefix);
+    output.push(HEX_UPPER[(byte >> 4) as usize] as char);
+    output.push(HEX_UPPER[(byte & 0x0f) as usize] as char);
+}
+
+#[inline(always)]
+fn normalize_percent_mode(options: Option<PercentOptions>) -> napi::Result<PercentMode> {
+    let Some(value) = options.unwrap_or_default().mode else {
+        return Ok(PercentMode::Component);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "component" => Ok(PercentMode::Component),
+        "path" => Ok(PercentMode::Path),
+        _ => Err(invalid_input(
+            "Percent mode must be one of: component, path",
+        )),
+    }
+}
+
+#[inline(always)]
+fn percent_is_safe(byte: u8, mode: &PercentMode) -> bool {
+    if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+        return true;
+    }
+    matches!(mode, PercentMode::Path) && PERCENT_PATH_SAFE.contains(&byte)
+}
+
+#[inline(always)]
+fn percent_encode_bytes(input: &[u8], mode: &PercentMode) -> String {
+    let mut output = String::with_capacity(input.len());
+    for byte in input {
+        if percent_is_safe(*byte, mode) {
+            output.push(*byte as char);
+        } else {
+            push_hex_escape(&mut output, '%', *byte);
+        }
+    }
+    output
+}
+
+#[inline(always)]
+pub(crate) fn percent_decode_bytes(input: &str) -> napi::Result<Vec<u8>> {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] != b'%' {
+            output.push(bytes[index]);
+            index += 1;
+            continue;
+        }
+
+        let high = bytes.get(index + 1).copied().and_then(hex_value);
+        let low = bytes.get(index + 2).copied().and_then(hex_value);
+        let (Some(high), Some(low)) = (high, low) else {
+            return Err(invalid_input(&format!(
+                "Invalid percent-encoded input: malformed escape at position {index}"
+            )));
+        };
+        output.push((high << 4) | low);
+        index += 3;
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn normalize_html_options(options: Option<HtmlOptions>) -> napi::Result<(HtmlEntityMode, bool)> {
+    let options = options.unwrap_or_default();
+    let mode = match options.mode {
+        None => HtmlEntityMode::Named,
+        Some(value) => match value.to_ascii_lowercase().as_str() {
+            "named" => HtmlEntityMode::Named,
+            "numeric" => HtmlEntityMode::Numeric,
+            _ => return Err(invalid_input("HTML mode must be one of: named, numeric")),
+        },
+    };
+    Ok((mode, options.ascii.unwrap_or(false)))
+}
+
+#[inline(always)]
+fn html_entity_name(ch: char) -> Option<&'static str> {
+    HTML_ENTITIES
+        .iter()
+        .find(|(_, value)| *value == ch)
+        .map(|(name, _)| *name)
+}
+
+#[inline(always)]
+fn html_entity_char(name: &str) -> Option<char> {
+    HTML_ENTITIES
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, value)| *value)
+}
+
+#[inline(always)]
+fn html_encode_text(input: &str, mode: &HtmlEntityMode, ascii: bool) -> String {
+    let mut output = String::with_capacity(input.len());
+    for ch in input.chars() {
+        let escape = matches!(ch, '&' | '<' | '>' | '"' | '\'') || (ascii && !ch.is_ascii());
+        if !escape {
+            output.push(ch);
+            continue;
+        }
+
+        let named = match mode {
+            HtmlEntityMode::Named if ch != '\'' => html_entity_name(ch),
+            _ => None,
+        };
+        match named {
+            Some(name) => {
+                output.push('&');
+                output.push_str(name);
+                output.push(';');
+            }
+            None => output.push_str(&format!("&#{};", ch as u32)),
+        }
+    }
+    output
+}
+
+#[inline(always)]
+fn html_numeric_char(body: &str) -> Option<char> {
+    let value = match body.strip_prefix(['x', 'X']) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => body.parse::<u32>().ok()?,
+    };
+    Some(char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+#[inline(always)]
+fn html_decode_text(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest[1..].find(';').and_then(|end| {
+            let body = &rest[1..end + 1];
+            let ch = match body.strip_prefix('#') {
+                Some(numeric) => html_numeric_char(numeric),
+                None => html_entity_char(body),
+            };
+            ch.map(|ch| (ch, end + 2))
+        });
+        match decoded {
+            Some((ch, consumed)) => {
+                output.push(ch);
+                rest = &rest[consumed..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+#[inline(always)]
+fn qp_is_literal(byte: u8) -> bool {
+    matches!(byte, 33..=60 | 62..=126)
+}
+
+#[inline(always)]
+fn qp_push(output: &mut String, line_length: &mut usize, token: &str) {
+    if *line_length + token.len() > QP_LINE_CHARS - 1 {
+        output.push_str("=\r\n");
+        *line_length = 0;
+    }
+    output.push_str(token);
+    *line_length += token.len();
+}
+
+#[inline(always)]
+fn qp_encode_bytes(input: &[u8], binary: bool) -> String {
+    let mut output = String::with_capacity(input.len() * 3 / 2);
+    let mut line_length = 0;
+    let mut index = 0;
+    while index < input.len() {
+        let byte = input[index];
+        let next = input.get(index + 1).copied();
+        if byte == b'\r' && next == Some(b'\n') {
+            output.push_str("\r\n");
+            line_length = 0;
+            index += 2;
+            continue;
+        }
+        if byte == b'\n' && !binary {
+            output.push_str("\r\n");
+            line_length = 0;
+            index += 1;
+            continue;
+        }
+
+        let line_end = matches!(next, None | Some(b'\r') | Some(b'\n'));
+        let mut token = String::with_capacity(3);
+        if qp_is_literal(byte) || (matches!(byte, b' ' | b'\t') && !line_end) {
+            token.push(byte as char);
+        } else {
+            push_hex_escape(&mut token, '=', byte);
+        }
+        qp_push(&mut output, &mut line_length, &token);
+        index += 1;
+    }
+    output
+}
+
+#[inline(always)]
+fn qp_decode_bytes(input: &str) -> napi::Result<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len());
+    let mut lines = input.split('\n').peekable();
+    while let Some(raw_line) = lines.next() {
+        let line = raw_line.strip_suffix('\r').unwrap_or(raw_line);
+        let line = line.trim_end_matches([' ', '\t']);
+        let (line, soft_break) = match line.strip_suffix('=') {
+            Some(rest) => (rest, true),
+            None => (line, false),
+        };
+
+        let bytes = line.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] != b'=' {
+                output.push(bytes[index]);
+                index += 1;
+                continue;
+            }
+            let high = bytes.get(index + 1).copied().and_then(hex_value);
+            let low = bytes.get(index + 2).copied().and_then(hex_value);
+            let (Some(high), Some(low)) = (high, low) else {
+                return Err(invalid_input(
+                    "Invalid quoted-printable input: malformed escape",
+                ));
+            };
+            output.push((high << 4) | low);
+            index += 3;
+        }
+        if !soft_break && lines.peek().is_some() {
+            output.extend_from_slice(b"\r\n");
+        }
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn normalize_encoded_word_encoding(
+    options: Option<EncodedWordOptions>,
+) -> napi::Result<EncodedWordEncoding> {
+    let Some(value) = options.unwrap_or_default().encoding else {
+        return Ok(EncodedWordEncoding::Base64);
+    };
+    match value.to_ascii_uppercase().as_str() {
+        "B" => Ok(EncodedWordEncoding::Base64),
+        "Q" => Ok(EncodedWordEncoding::Quoted),
+        _ => Err(invalid_input("Encoded-word encoding must be one of: B, Q")),
+    }
+}
+
+#[inline(always)]
+fn encoded_word_q(input: &[u8]) -> String {
+    let mut output = String::with_capacity(input.len());
+    for byte in input {
+        match byte {
+            b' ' => output.push('_'),
+            byte if byte.is_ascii_alphanumeric() || b"!*+-/".contains(byte) => {
+                output.push(*byte as char)
+            }
+            _ => push_hex_escape(&mut output, '=', *byte),
+        }
+    }
+    output
+}
+
+#[inline(always)]
+fn encoded_word_q_decode(input: &str) -> napi::Result<Vec<u8>> {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'_' => output.push(b' '),
+            b'=' => {
+                let high = bytes.get(index + 1).copied().and_then(hex_value);
+                let low = bytes.get(index + 2).copied().and_then(hex_value);
+                let (Some(high), Some(low)) = (high, low) else {
+                    return Err(invalid_input(
+                        "Invalid encoded-word input: malformed escape",
+                    ));
+                };
+                output.push((high << 4) | low);
+                index += 2;
+            }
+            byte => output.push(byte),
+        }
+        index += 1;
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn encoded_word_text(chunk: &str, encoding: &EncodedWordEncoding) -> String {
+    match encoding {
+        EncodedWordEncoding::Base64 => base64::engine::general_purpose::STANDARD.encode(chunk),
+        EncodedWordEncoding::Quoted => encoded_word_q(chunk.as_bytes()),
+    }
+}
+
+#[inline(always)]
+fn encoded_word_encode_text(input: &str, encoding: &EncodedWordEncoding) -> String {
+    let tag = match encoding {
+        EncodedWordEncoding::Base64 => 'B',
+        EncodedWordEncoding::Quoted => 'Q',
+    };
+    let overhead = ENCODED_WORD_CHARSET.len() + 7;
+    let mut words = Vec::new();
+    let mut chunk = String::new();
+    for ch in input.chars() {
+        let mut candidate = chunk.clone();
+        candidate.push(ch);
+        if !chunk.is_empty()
+            && encoded_word_text(&candidate, encoding).len() + overhead > ENCODED_WORD_MAX_CHARS
+        {
+            words.push(std::mem::take(&mut chunk));
+            candidate = ch.to_string();
+        }
+        chunk = candidate;
+    }
+    if !chunk.is_empty() || words.is_empty() {
+        words.push(chunk);
+    }
+
+    words
+        .iter()
+        .map(|word| {
+            format!(
+                "=?{ENCODED_WORD_CHARSET}?{tag}?{}?=",
+                encoded_word_text(word, encoding)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[inline(always)]
+fn encoded_word_charset_text(charset: &str, bytes: Vec<u8>) -> napi::Result<String> {
+    let charset = charset.split('*').next().unwrap_or_default();
+    match charset.to_ascii_lowercase().as_str() {
+        "utf-8" | "utf8" | "us-ascii" => decoded_text(bytes),
+        "iso-8859-1" | "latin1" => Ok(bytes.iter().map(|byte| *byte as char).collect()),
+        _ => Err(invalid_input(&format!(
+            "Unsupported encoded-word charset: {charset}"
+        ))),
+    }
+}
+
+#[inline(always)]
+fn encoded_word_decode_word(word: &str) -> Option<napi::Result<String>> {
+    let body = word.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut parts = body.splitn(3, '?');
+    let (charset, encoding, text) = (parts.next()?, parts.next()?, parts.next()?);
+    if text.contains('?') || text.contains(' ') {
+        return None;
+    }
+
+    let bytes = match encoding.to_ascii_uppercase().as_str() {
+        "B" => base64::engine::general_purpose::STANDARD
+            .decode(text)
+            .map_err(|error| invalid_input(&format!("Invalid encoded-word input: {error}"))),
+        "Q" => encoded_word_q_decode(text),
+        _ => return None,
+    };
+    Some(bytes.and_then(|bytes| encoded_word_charset_text(charset, bytes)))
+}
+
+#[inline(always)]
+fn encoded_word_decode_text(input: &str) -> napi::Result<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut pending_space = String::new();
+    let mut previous_was_word = false;
+    let mut rest = input;
+    while !rest.is_empty() {
+        let space_end = rest
+            .find(|ch: char| !ch.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        if space_end > 0 {
+            pending_space.push_str(&rest[..space_end]);
+            rest = &rest[space_end..];
+            continue;
+        }
+
+        let token_end = rest
+            .find(|ch: char| ch.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let token = &rest[..token_end];
+        rest = &rest[token_end..];
+        match encoded_word_decode_word(token) {
+            Some(decoded) => {
+                if !previous_was_word {
+                    output.push_str(&pending_space);
+                }
+                output.push_str(&decoded?);
+                previous_was_word = true;
+            }
+            None => {
+                output.push_str(&pending_space);
+                output.push_str(token);
+                previous_was_word = false;
+            }
+        }
+        pending_space.clear();
+    }
+    output.push_str(&pending_space);
+    Ok(output)
+}
+
+#[inline(always)]
+fn bech32_constant(variant: &Bech32Variant) -> u32 {
+    match variant {
+        Bech32Variant::Bech32 => BECH32_CONST,
+        Bech32Variant::Bech32m => BECH32M_CONST,
+    }
+}
+
+#[inline(always)]
+fn bech32_variant_name(variant: &Bech32Variant) -> &'static str {
+    match variant {
+        Bech32Variant::Bech32 => "bech32",
+        Bech32Variant::Bech32m => "bech32m",
+    }
+}
+
+#[inline(always)]
+fn bech32_polymod(values: &[u8]) -> u32 {
+    values.iter().fold(1_u32, |checksum, value| {
+        let top = checksum >> 25;
+        let mut checksum = ((checksum & 0x01ff_ffff) << 5) ^ (*value as u32);
+        for (bit, generator) in BECH32_GENERATOR.iter().enumerate() {
+            if (top >> bit) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+        checksum
+    })
+}
+
+#[inline(always)]
+fn bech32_hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut output: Vec<u8> = hrp.bytes().map(|byte| byte >> 5).collect();
+    output.push(0);
+    output.extend(hrp.bytes().map(|byte| byte & 0x1f));
+    output
+}
+
+#[inline(always)]
+fn bech32_create_checksum(hrp: &str, data: &[u8], variant: &Bech32Variant) -> Vec<u8> {
+    let mut values = bech32_hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0; BECH32_CHECKSUM_CHARS]);
+    let polymod = bech32_polymod(&values) ^ bech32_constant(variant);
+    (0..BECH32_CHECKSUM_CHARS)
+        .map(|index| ((polymod >> (5 * (5 - index))) & 0x1f) as u8)
+        .collect()
+}
+
+#[inline(always)]
+fn bech32_verify_checksum(hrp: &str, data: &[u8], variant: &Bech32Variant) -> bool {
+    let mut values = bech32_hrp_expand(hrp);
+    values.extend_from_slice(data);
+    bech32_polymod(&values) == bech32_constant(variant)
+}
+
+#[inline(always)]
+fn convert_bits(input: &[u8], from: u32, to: u32, pad: bool) -> napi::Result<Vec<u8>> {
+    let max_value = (1_u32 << to) - 1;
+    let mut accumulator = 0_u32;
+    let mut bits = 0_u32;
+    let mut output = Vec::with_capacity(input.len() * from as usize / to as usize + 1);
+    for value in input {
+        accumulator = (accumulator << from) | (*value as u32);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            output.push(((accumulator >> bits) & max_value) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            output.push(((accumulator << (to - bits)) & max_value) as u8);
+        }
+    } else if bits >= from || ((accumulator << (to - bits)) & max_value) != 0 {
+        return Err(invalid_input("Invalid bech32 input: non-zero padding"));
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn normalize_bech32_hrp(hrp: &str) -> napi::Result<String> {
+    if hrp.is_empty() || hrp.len() > BECH32_MAX_HRP_LENGTH {
+        return Err(invalid_input("Bech32 HRP must be 1 to 83 characters"));
+    }
+    if !hrp.bytes().all(|byte| (33..=126).contains(&byte)) {
+        return Err(invalid_input(
+            "Bech32 HRP must contain only printable ASCII characters",
+        ));
+    }
+    if hrp.bytes().any(|byte| byte.is_ascii_lowercase())
+        && hrp.bytes().any(|byte| byte.is_ascii_uppercase())
+    {
+        return Err(invalid_input(
+            "Bech32 HRP must not mix upper and lower case",
+        ));
+    }
+    Ok(hrp.to_ascii_lowercase())
+}
+
+#[inline(always)]
+fn validate_witness_program(
+    version: u32,
+    program: &[u8],
+    variant: &Bech32Variant,
+) -> napi::Result<()> {
+    if version > BECH32_MAX_WITNESS_VERSION {
+        return Err(invalid_input("Witness version must be between 0 and 16"));
+    }
+    match (version, variant) {
+        (0, Bech32Variant::Bech32m) => {
+            return Err(invalid_input("Witness version 0 must use bech32"));
+        }
+        (1.., Bech32Variant::Bech32) => {
+            return Err(invalid_input(
+                "Witness version 1 and above must use bech32m",
+            ));
+        }
+        _ => {}
+    }
+    if !(BECH32_MIN_PROGRAM_BYTES..=BECH32_MAX_PROGRAM_BYTES).contains(&program.len()) {
+        return Err(invalid_input("Witness program must be 2 to 40 bytes"));
+    }
+    if version == 0 && program.len() != 20 && program.len() != 32 {
+        return Err(invalid_input(
+            "Witness version 0 program must be 20 or 32 bytes",
+        ));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn bech32_encode_bytes(
+    hrp: &str,
+    data: &[u8],
+    version: Option<u32>,
+    variant: &Bech32Variant,
+) -> napi::Result<String> {
+    let hrp = normalize_bech32_hrp(hrp)?;
+    let mut words = Vec::with_capacity(data.len() * 8 / 5 + 2);
+    if let Some(version) = version {
+        validate_witness_program(version, data, variant)?;
+        words.push(version as u8);
+    }
+    words.extend(convert_bits(data, 8, 5, true)?);
+    let checksum = bech32_create_checksum(&hrp, &words, variant);
+    words.extend(checksum);
+    if hrp.len() + 1 + words.len() > BECH32_MAX_LENGTH {
+        return Err(invalid_input(&format!(
+            "{} output must be at most 90 characters",
+            bech32_variant_name(variant)
+        )));
+    }
+
+    let mut output = hrp;
+    output.push(BECH32_SEPARATOR);
+    output.extend(
+        words
+            .iter()
+            .map(|word| BECH32_CHARSET[*word as usize] as char),
+    );
+    Ok(output)
+}
+
+#[inline(always)]
+fn bech32_decode_bytes(
+    input: &str,
+    witness: bool,
+    variant: &Bech32Variant,
+) -> napi::Result<Bech32Decoded> {
+    let name = bech32_variant_name(variant);
+    if input.len() > BECH32_MAX_LENGTH {
+        return Err(invalid_input(&format!(
+            "Invalid {name} input: longer than 90 characters"
+        )));
+    }
+    if input.bytes().any(|byte| byte.is_ascii_lowercase())
+        && input.bytes().any(|byte| byte.is_ascii_uppercase())
+    {
+        return Err(invalid_input(&format!("Invalid {name} input: mixed case")));
+    }
+
+    let lowered = input.to_ascii_lowercase();
+    let Some(separator) = lowered.rfind(BECH32_SEPARATOR) else {
+        return Err(invalid_input(&format!(
+            "Invalid {name} input: missing separator"
+        )));
+    };
+    let (hrp, data) = (&lowered[..separator], &lowered[separator + 1..]);
+    let hrp = normalize_bech32_hrp(hrp)
+        .map_err(|error| invalid_input(&format!("Invalid {name} input: {}", error.reason)))?;
+    if data.len() < BECH32_CHECKSUM_CHARS {
+        return Err(invalid_input(&format!(
+            "Invalid {name} input: checksum is too short"
+        )));
+    }
+
+    let mut words = Vec::with_capacity(data.len());
+    for (index, ch) in data.bytes().enumerate() {
+        let Some(word) = BECH32_CHARSET.iter().position(|value| *value == ch) else {
+            return Err(invalid_input(&format!(
+                "Invalid {name} input: unexpected character {:?} at position {}",
+                ch as char,
+                separator + 1 + index
+            )));
+        };
+        words.push(word as u8);
+    }
+    if !bech32_verify_checksum(&hrp, &words, variant) {
+        return Err(invalid_input(&format!(
+            "Invalid {name} input: checksum mismatch"
+        )));
+    }
+
+    words.truncate(words.len() - BECH32_CHECKSUM_CHARS);
+    if !witness {
+        let bytes = convert_bits(&words, 5, 8, false)?;
+        return Ok(Bech32Decoded {
+            hrp,
+            version: None,
+            data: bytes.into(),
+        });
+    }
+
+    let Some((version, program)) = words.split_first() else {
+        return Err(invalid_input(&format!(
+            "Invalid {name} input: missing witness version"
+        )));
+    };
+    let version = *version as u32;
+    let program = convert_bits(program, 5, 8, false)?;
+    validate_witness_program(version, &program, variant)
+        .map_err(|error| invalid_input(&format!("Invalid {name} input: {}", error.reason)))?;
+    Ok(Bech32Decoded {
+        hrp,
+        version: Some(version),
+        data: program.into(),
+    })
+}
+
+#[inline(always)]
+fn normalize_bech32_version(options: Option<Bech32EncodeOptions>) -> Option<u32> {
+    options.unwrap_or_default().version
+}
+
+#[inline(always)]
+fn normalize_bech32_witness(options: Option<Bech32DecodeOptions>) -> bool {
+    options.unwrap_or_default().witness.unwrap_or(false)
+}
+
+#[napi(namespace = "base58", js_name = "encode")]
+pub fn base58_encode(input: ByteInput, options: Option<Base58Options>) -> napi::Result<String> {
+    let alphabet = normalize_base58_alphabet(options)?;
+    Ok(base58_encode_bytes(input_bytes(&input), alphabet))
+}
+
+#[napi(namespace = "base58", js_name = "decode")]
+pub fn base58_decode(input: ByteInput, options: Option<Base58Options>) -> napi::Result<String> {
+    let alphabet = normalize_base58_alphabet(options)?;
+    decoded_text(base58_decode_bytes(input_text(&input)?, alphabet)?)
+}
+
+#[napi(namespace = "base58", js_name = "decodeBytes")]
+pub fn base58_decode_buffer(
+    input: ByteInput,
+    options: Option<Base58Options>,
+) -> napi::Result<Buffer> {
+    let alphabet = normalize_base58_alphabet(options)?;
+    Ok(base58_decode_bytes(input_text(&input)?, alphabet)?.into())
+}
+
+#[napi(namespace = "base58check", js_name = "encode")]
+pub fn base58check_encode(
+    input: ByteInput,
+    options: Option<Base58Options>,
+) -> napi::Result<String> {
+    let alphabet = normalize_base58_alphabet(options)?;
+    Ok(base58check_encode_bytes(input_bytes(&input), alphabet))
+}
+
+#[napi(namespace = "base58check", js_name = "decode")]
+pub fn base58check_decode(
+    input: ByteInput,
+    options: Option<Base58Options>,
+) -> napi::Result<String> {
+    let alphabet = normalize_base58_alphabet(options)?;
+    decoded_text(base58check_decode_bytes(input_text(&input)?, alphabet)?)
+}
+
+#[napi(namespace = "base58check", js_name = "decodeBytes")]
+pub fn base58check_decode_buffer(
+    input: ByteInput,
+    options: Option<Base58Options>,
+) -> napi::Result<Buffer> {
+    let alphabet = normalize_base58_alphabet(options)?;
+    Ok(base58check_decode_bytes(input_text(&input)?, alphabet)?.into())
+}
+
+#[napi(namespace = "base85", js_name = "encode")]
+pub fn base85_encode(input: ByteInput, options: Option<Base85Options>) -> napi::Result<String> {
+    let variant = normalize_base85_variant(options)?;
+    base85_encode_bytes(input_bytes(&input), &variant)
+}
+
+#[napi(namespace = "base85", js_name = "decode")]
+pub fn base85_decode(input: ByteInput, options: Option<Base85Options>) -> napi::Result<String> {
+    let variant = normalize_base85_variant(options)?;
+    decoded_text(base85_decode_bytes(input_text(&input)?, &variant)?)
+}
+
+#[napi(namespace = "base85", js_name = "decodeBytes")]
+pub fn base85_decode_buffer(
+    input: ByteInput,
+    options: Option<Base85Options>,
+) -> napi::Result<Buffer> {
+    let variant = normalize_base85_variant(options)?;
+    Ok(base85_decode_bytes(input_text(&input)?, &variant)?.into())
+}
+
+#[napi(namespace = "baseN", js_name = "encode")]
+pub fn base_n_encode(input: IntegerInput, alphabet: String) -> napi::Result<String> {
+    let alphabet = normalize_base_n_alphabet(&alphabet)?;
+    base_n_encode_input(&input, &alphabet)
+}
+
+#[napi(namespace = "baseN", js_name = "decode")]
+pub fn base_n_decode(input: ByteInput, alphabet: String) -> napi::Result<String> {
+    let alphabet = normalize_base_n_alphabet(&alphabet)?;
+    decoded_text(radix_decode(input_text(&input)?, &alphabet, "baseN")?)
+}
+
+#[napi(namespace = "baseN", js_name = "decodeBytes")]
+pub fn base_n_decode_buffer(input: ByteInput, alphabet: String) -> napi::Result<Buffer> {
+    let alphabet = normalize_base_n_alphabet(&alphabet)?;
+    Ok(radix_decode(input_text(&input)?, &alphabet, "baseN")?.into())
+}
+
+#[napi(namespace = "baseN", js_name = "decodeBigInt")]
+pub fn base_n_decode_bigint(input: ByteInput, alphabet: String) -> napi::Result<BigInt> {
+    let alphabet = normalize_base_n_alphabet(&alphabet)?;
+    let bytes = radix_decode(input_text(&input)?, &alphabet, "baseN")?;
+    Ok(bytes_to_bigint(&bytes))
+}
+
+#[napi(namespace = "bech32", js_name = "encode")]
+pub fn bech32_encode(
+    hrp: String,
+    data: ByteInput,
+    options: Option<Bech32EncodeOptions>,
+) -> napi::Result<String> {
+    let version = normalize_bech32_version(options);
+    bech32_encode_bytes(&hrp, input_bytes(&data), version, &Bech32Variant::Bech32)
+}
+
+#[napi(namespace = "bech32", js_name = "decode")]
+pub fn bech32_decode(
+    input: String,
+    options: Option<Bech32DecodeOptions>,
+) -> napi::Result<Bech32Decoded> {
+    let witness = normalize_bech32_witness(options);
+    bech32_decode_bytes(&input, witness, &Bech32Variant::Bech32)
+}
+
+#[napi(namespace = "bech32m", js_name = "encode")]
+pub fn bech32m_encode(
+    hrp: String,
+    data: ByteInput,
+    options: Option<Bech32EncodeOptions>,
+) -> napi::Result<String> {
+    let version = normalize_bech32_version(options);
+    bech32_encode_bytes(&hrp, input_bytes(&data), version, &Bech32Variant::Bech32m)
+}
+
+#[napi(namespace = "bech32m", js_name = "decode")]
+pub fn bech32m_decode(
+    input: String,
+    options: Option<Bech32DecodeOptions>,
+) -> napi::Result<Bech32Decoded> {
+    let witness = normalize_bech32_witness(options);
+    bech32_decode_bytes(&input, witness, &Bech32Variant::Bech32m)
+}
+
+#[napi(namespace = "percent", js_name = "encode")]
+pub fn percent_encode(input: ByteInput, options: Option<PercentOptions>) -> napi::Result<String> {
+    let mode = normalize_percent_mode(options)?;
+    Ok(percent_encode_bytes(input_bytes(&input), &mode))
+}
+
+#[napi(namespace = "percent", js_name = "decode")]
+pub fn percent_decode(input: ByteInput) -> napi::Result<String> {
+    decoded_text(percent_decode_bytes(input_text(&input)?)?)
+}
+
+#[napi(namespace = "percent", js_name = "decodeBytes")]
+pub fn percent_decode_buffer(input: ByteInput) -> napi::Result<Buffer> {
+    Ok(percent_decode_bytes(input_text(&input)?)?.into())
+}
+
+#[napi(namespace = "html", js_name = "encode")]
+pub fn html_encode(input: String, options: Option<HtmlOptions>) -> napi::Result<String> {
+    let (mode, ascii) = normalize_html_options(options)?;
+    Ok(html_encode_text(&input, &mode, ascii))
+}
+
+#[napi(namespace = "html", js_name = "decode")]
+pub fn html_decode(input: String) -> String {
+    html_decode_text(&input)
+}
+
+#[napi(namespace = "quotedPrintable", js_name = "encode")]
+pub fn quoted_printable_encode(
+    input: ByteInput,
+    options: Option<QuotedPrintableOptions>,
+) -> String {
+    let binary = options
+        .unwrap_or_default()
+        .binary
+        .unwrap_or(matches!(input, Either::B(_)));
+    qp_encode_bytes(input_bytes(&input), binary)
+}
+
+#[napi(namespace = "quotedPrintable", js_name = "decode")]
+pub fn quoted_printable_decode(input: ByteInput) -> napi::Result<String> {
+    decoded_text(qp_decode_bytes(input_text(&input)?)?)
+}
+
+#[napi(namespace = "quotedPrintable", js_name = "decodeBytes")]
+pub fn quoted_printable_decode_buffer(input: ByteInput) -> napi::Result<Buffer> {
+    Ok(qp_decode_bytes(input_text(&input)?)?.into())
+}
+
+#[napi(namespace = "encodedWord", js_name = "encode")]
+pub fn encoded_word_encode(
+    input: String,
+    options: Option<EncodedWordOptions>,
+) -> napi::Result<String> {
+    let encoding = normalize_encoded_word_encoding(options)?;
+    Ok(encoded_word_encode_text(&input, &encoding))
+}
+
+#[napi(namespace = "encodedWord", js_name = "decode")]
+pub fn encoded_word_decode(input: String) -> napi::Result<String> {
+    encoded_word_decode_text(&input)
+}
@@ -0,0 +1,482 @@
+#![allow(dead_code)]
+use std::collections::HashMap;
+
+use napi::bindgen_prelude::{AbortSignal, AsyncTask};
+use napi::{Env, Task};
+use napi_derive::napi;
+
+use crate::classic::vigenere_decrypt;
+use crate::crypto::rotate_ascii_char;
+use crate::detect::{ENGLISH_FREQUENCIES, english_chi_squared, letter_counts};
+
+const ALPHABET_SIZE: u8 = 26;
+const DEFAULT_MAX_KEY_LENGTH: u32 = 20;
+const MAX_KEY_LENGTH: u32 = 100;
+const KEY_LENGTH_IOC_RATIO: f64 = 0.9;
+const MIN_COLUMN_LETTERS: usize = 6;
+const KEY_LENGTH_PENALTY: f64 = 2.0;
+const MAX_CANDIDATE_LENGTHS: usize = 6;
+const KASISKI_NGRAM: usize = 3;
+const KASISKI_SUPPORT: f64 = 0.5;
+const KASISKI_IOC_RATIO: f64 = 0.75;
+const FITNESS_TOLERANCE: f64 = 0.01;
+const REFINE_PASSES: usize = 2;
+const UNSEEN_BIGRAM_DISCOUNT: f64 = 0.5;
+const MIN_FITNESS: f64 = -10.0;
+
+const ENGLISH_BIGRAMS: &[(&str, f64)] = &[
+    ("th", 3.56),
+    ("he", 3.07),
+    ("in", 2.43),
+    ("er", 2.05),
+    ("an", 1.99),
+    ("re", 1.85),
+    ("on", 1.76),
+    ("at", 1.49),
+    ("en", 1.45),
+    ("nd", 1.35),
+    ("ti", 1.34),
+    ("es", 1.34),
+    ("or", 1.28),
+    ("te", 1.20),
+    ("of", 1.17),
+    ("ed", 1.17),
+    ("is", 1.13),
+    ("it", 1.12),
+    ("al", 1.09),
+    ("ar", 1.07),
+    ("st", 1.05),
+    ("to", 1.04),
+    ("nt", 1.04),
+    ("ng", 0.95),
+    ("se", 0.93),
+    ("ha", 0.93),
+    ("as", 0.87),
+    ("ou", 0.87),
+    ("io", 0.83),
+    ("le", 0.83),
+    ("ve", 0.83),
+    ("co", 0.79),
+    ("me", 0.79),
+    ("de", 0.76),
+    ("hi", 0.76),
+    ("ri", 0.73),
+    ("ro", 0.73),
+    ("ic", 0.70),
+    ("ne", 0.69),
+    ("ea", 0.69),
+    ("ra", 0.69),
+    ("ce", 0.65),
+];
+
+#[napi(object)]
+pub struct RotCandidate {
+    pub shift: u32,
+    pub text: String,
+    pub score: f64,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct VigenereCrackOptions {
+    #[napi(js_name = "maxKeyLength")]
+    pub max_key_length: Option<u32>,
+}
+
+#[napi(object)]
+pub struct VigenereCrack {
+    pub key: String,
+    #[napi(js_name = "keyLength")]
+    pub key_length: u32,
+    pub plaintext: String,
+    pub score: f64,
+}
+
+pub struct VigenereCrackTask {
+    ciphertext: String,
+    max_length: usize,
+}
+
+#[napi(object)]
+pub struct LetterFrequency {
+    pub letter: String,
+    pub count: u32,
+    pub frequency: f64,
+}
+
+#[napi(object)]
+pub struct FrequencyReport {
+    pub total: u32,
+    pub letters: Vec<LetterFrequency>,
+    #[napi(js_name = "indexOfCoincidence")]
+    pub index_of_coincidence: f64,
+    #[napi(js_name = "chiSquared")]
+    pub chi_squared: f64,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn letter_indices(text: &str) -> Vec<u8> {
+    text.bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|byte| byte.to_ascii_lowercase() - b'a')
+        .collect()
+}
+
+#[inline(always)]
+fn bigram_table() -> Vec<[f64; 26]> {
+    let mut table = vec![[0.0; 26]; 26];
+    for (first, row) in table.iter_mut().enumerate() {
+        for (second, value) in row.iter_mut().enumerate() {
+            *value =
+                (ENGLISH_FREQUENCIES[first] * ENGLISH_FREQUENCIES[second] * UNSEEN_BIGRAM_DISCOUNT)
+                    .log10();
+        }
+    }
+    for (bigram, percent) in ENGLISH_BIGRAMS {
+        let bytes = bigram.as_bytes();
+        table[(bytes[0] - b'a') as usize][(bytes[1] - b'a') as usize] = (percent / 100.0).log10();
+    }
+    table
+}
+
+#[inline(always)]
+fn word_pairs(text: &str) -> (Vec<u8>, Vec<usize>) {
+    let mut letters = Vec::new();
+    let mut pairs = Vec::new();
+    let mut previous_letter = false;
+    for byte in text.bytes() {
+        if byte.is_ascii_alphabetic() {
+            if previous_letter {
+                pairs.push(letters.len() - 1);
+            }
+            letters.push(byte.to_ascii_lowercase() - b'a');
+            previous_letter = true;
+        } else {
+            previous_letter = false;
+        }
+    }
+    (letters, pairs)
+}
+
+#[inline(always)]
+fn pairs_fitness(letters: &[u8], pairs: &[usize], shifts: &[u8], table: &[[f64; 26]]) -> f64 {
+    let plain = |index: usize| {
+        let shift = shifts[index % shifts.len()];
+        ((letters[index] + ALPHABET_SIZE - shift) % ALPHABET_SIZE) as usize
+    };
+    pairs
+        .iter()
+        .map(|index| table[plain(*index)][plain(*index + 1)])
+        .sum()
+}
+
+#[inline(always)]
+fn shifted_fitness(letters: &[u8], pairs: &[usize], shifts: &[u8], table: &[[f64; 26]]) -> f64 {
+    if pairs.is_empty() {
+        return MIN_FITNESS;
+    }
+    pairs_fitness(letters, pairs, shifts, table) / pairs.len() as f64
+}
+
+#[inline(always)]
+fn column_pairs(pairs: &[usize], length: usize) -> Vec<Vec<usize>> {
+    let mut columns = vec![Vec::new(); length];
+    for index in pairs {
+        let (first, second) = (index % length, (index + 1) % length);
+        columns[first].push(*index);
+        if second != first {
+            columns[second].push(*index);
+        }
+    }
+    columns
+}
+
+#[inline(always)]
+pub(crate) fn english_fitness(text: &str) -> f64 {
+    let (letters, pairs) = word_pairs(text);
+    shifted_fitness(&letters, &pairs, &[0], &bigram_table())
+}
+
+#[inline(always)]
+fn round_score(value: f64) -> f64 {
+    (value * 10_000.0).round() / 10_000.0
+}
+
+#[inline(always)]
+fn index_of_coincidence(counts: &[usize; 26], total: usize) -> f64 {
+    if total < 2 {
+        return 0.0;
+    }
+    let pairs: usize = counts
+        .iter()
+        .map(|count| count * count.saturating_sub(1))
+        .sum();
+    pairs as f64 / (total * (total - 1)) as f64
+}
+
+#[inline(always)]
+fn column_counts(letters: &[u8], length: usize, column: usize) -> ([usize; 26], usize) {
+    let mut counts = [0_usize; 26];
+    let mut total = 0;
+    for letter in letters.iter().skip(column).step_by(length) {
+        counts[*letter as usize] += 1;
+        total += 1;
+    }
+    (counts, total)
+}
+
+#[inline(always)]
+fn average_column_ioc(letters: &[u8], length: usize) -> f64 {
+    let sum: f64 = (0..length)
+        .map(|column| {
+            let (counts, total) = column_counts(letters, length, column);
+            index_of_coincidence(&counts, total)
+        })
+        .sum();
+    sum / length as f64
+}
+
+#[inline(always)]
+fn kasiski_distances(letters: &[u8]) -> Vec<usize> {
+    let mut seen: HashMap<&[u8], usize> = HashMap::new();
+    let mut distances = Vec::new();
+    for (index, ngram) in letters.windows(KASISKI_NGRAM).enumerate() {
+        if let Some(previous) = seen.insert(ngram, index) {
+            distances.push(index - previous);
+        }
+    }
+    distances
+}
+
+#[inline(always)]
+fn kasiski_supports(distances: &[usize], length: usize) -> bool {
+    let divisible = distances
+        .iter()
+        .filter(|distance| distance.is_multiple_of(length))
+        .count();
+    divisible as f64 >= KASISKI_SUPPORT * distances.len() as f64
+}
+
+#[inline(always)]
+fn candidate_key_lengths(letters: &[u8], max_length: usize) -> Vec<usize> {
+    let max_length = max_length.min(letters.len() / MIN_COLUMN_LETTERS).max(1);
+    let scores: Vec<f64> = (1..=max_length)
+        .map(|length| average_column_ioc(letters, length))
+        .collect();
+    let best = scores.iter().copied().fold(0.0, f64::max);
+    let distances = kasiski_distances(letters);
+    let mut selected: Vec<usize> = (1..=max_length)
+        .filter(|length| {
+            scores[length - 1] >= KEY_LENGTH_IOC_RATIO * best
+                || (!distances.is_empty()
+                    && scores[length - 1] >= KASISKI_IOC_RATIO * best
+                    && kasiski_supports(&distances, *length))
+        })
+        .collect();
+    selected.sort_by(|left, right| scores[right - 1].total_cmp(&scores[left - 1]));
+    selected.truncate(MAX_CANDIDATE_LENGTHS);
+    (1..=max_length)
+        .filter(|length| {
+            selected
+                .iter()
+                .any(|selected| selected.is_multiple_of(*length))
+        })
+        .collect()
+}
+
+#[inline(always)]
+fn best_column_shift(letters: &[u8], length: usize, column: usize) -> u8 {
+    let (counts, total) = column_counts(letters, length, column);
+    (0..ALPHABET_SIZE)
+        .map(|shift| {
+            let mut shifted = [0_usize; 26];
+            for (letter, count) in counts.iter().enumerate() {
+                shifted[(letter + ALPHABET_SIZE as usize - shift as usize) % 26] += count;
+            }
+            (shift, english_chi_squared(&shifted, total))
+        })
+        .min_by(|left, right| left.1.total_cmp(&right.1))
+        .map(|(shift, _)| shift)
+        .unwrap_or(0)
+}
+
+#[inline(always)]
+fn refine_shifts(letters: &[u8], pairs: &[usize], shifts: &mut [u8], table: &[[f64; 26]]) -> f64 {
+    let columns = column_pairs(pairs, shifts.len());
+    let mut best = pairs_fitness(letters, pairs, shifts, table);
+    for _ in 0..REFINE_PASSES {
+        let mut improved = false;
+        for (column, touching) in columns.iter().enumerate() {
+            let mut chosen = shifts[column];
+            let rest = best - pairs_fitness(letters, touching, shifts, table);
+            for shift in 0..ALPHABET_SIZE {
+                shifts[column] = shift;
+                let fitness = rest + pairs_fitness(letters, touching, shifts, table);
+                if fitness > best {
+                    best = fitness;
+                    chosen = shift;
+                    improved = true;
+                }
+            }
+            shifts[column] = chosen;
+        }
+        if !improved {
+            break;
+        }
+    }
+    shifted_fitness(letters, pairs, shifts, table)
+}
+
+#[inline(always)]
+fn shortest_key_period(key: &[u8]) -> usize {
+    (1..key.len())
+        .filter(|period| key.len().is_multiple_of(*period))
+        .find(|period| {
+            key.iter()
+                .enumerate()
+                .all(|(index, shift)| *shift == key[index % period])
+        })
+        .unwrap_or(key.len())
+}
+
+#[napi(namespace = "crack", js_name = "rot")]
+pub fn crack_rot(ciphertext: String) -> Vec<RotCandidate> {
+    let mut candidates: Vec<RotCandidate> = (0..ALPHABET_SIZE)
+        .map(|shift| {
+            let text: String = ciphertext
+                .chars()
+                .map(|ch| rotate_ascii_char(ch, (ALPHABET_SIZE - shift) % ALPHABET_SIZE))
+                .collect();
+            let score = english_fitness(&text);
+            RotCandidate {
+                shift: shift as u32,
+                text,
+                score,
+            }
+        })
+        .collect();
+    candidates.sort_by(|left, right| right.score.total_cmp(&left.score));
+    for candidate in &mut candidates {
+        candidate.score = round_score(candidate.score);
+    }
+    candidates
+}
+
+#[inline(always)]
+fn normalize_max_key_length(options: Option<VigenereCrackOptions>) -> napi::Result<usize> {
+    let max_length = options
+        .unwrap_or_default()
+        .max_key_length
+        .unwrap_or(DEFAULT_MAX_KEY_LENGTH);
+    if max_length == 0 || max_length > MAX_KEY_LENGTH {
+        return Err(invalid_input("maxKeyLength must be between 1 and 100"));
+    }
+    Ok(max_length as usize)
+}
+
+fn crack_vigenere_with(ciphertext: &str, max_length: usize) -> napi::Result<VigenereCrack> {
+    let letters = letter_indices(ciphertext);
+    if letters.is_empty() {
+        return Err(invalid_input("Ciphertext must contain letters"));
+    }
+
+    let (_, pairs) = word_pairs(ciphertext);
+    let table = bigram_table();
+    let mut best: Option<(Vec<u8>, f64)> = None;
+    for length in candidate_key_lengths(&letters, max_length) {
+        let mut shifts: Vec<u8> = (0..length)
+            .map(|column| best_column_shift(&letters, length, column))
+            .collect();
+        let fitness = refine_shifts(&letters, &pairs, &mut shifts, &table);
+        shifts.truncate(shortest_key_period(&shifts));
+        let fitness = fitness - KEY_LENGTH_PENALTY * shifts.len() as f64 / letters.len() as f64;
+        if best
+            .as_ref()
+            .is_none_or(|(_, current)| fitness > current + FITNESS_TOLERANCE)
+        {
+            best = Some((shifts, fitness));
+        }
+    }
+    let (shifts, _) = best.ok_or_else(|| invalid_input("Ciphertext must contain letters"))?;
+    let length = shifts.len();
+    let key: String = shifts.iter().map(|shift| (b'A' + shift) as char).collect();
+    let plaintext = vigenere_decrypt(ciphertext.to_string(), key.clone())?;
+    let score = round_score(english_fitness(&plaintext));
+    Ok(VigenereCrack {
+        key,
+        key_length: length as u32,
+        plaintext,
+        score,
+    })
+}
+
+impl Task for VigenereCrackTask {
+    type Output = VigenereCrack;
+    type JsValue = VigenereCrack;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        crack_vigenere_with(&self.ciphertext, self.max_length)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+#[napi(namespace = "crack", js_name = "vigenere")]
+pub fn crack_vigenere(
+    ciphertext: String,
+    options: Option<VigenereCrackOptions>,
+) -> napi::Result<VigenereCrack> {
+    let max_length = normalize_max_key_length(options)?;
+    crack_vigenere_with(&ciphertext, max_length)
+}
+
+#[napi(namespace = "crack", js_name = "vigenereAsync")]
+pub fn crack_vigenere_async(
+    ciphertext: String,
+    options: Option<VigenereCrackOptions>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<VigenereCrackTask>> {
+    let max_length = normalize_max_key_length(options)?;
+    Ok(AsyncTask::with_optional_signal(
+        VigenereCrackTask {
+            ciphertext,
+            max_length,
+        },
+        signal,
+    ))
+}
+
+#[napi(namespace = "analysis", js_name = "frequency")]
+pub fn analysis_frequency(text: String) -> FrequencyReport {
+    let (counts, total) = letter_counts(&text);
+    let mut letters: Vec<LetterFrequency> = counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(index, count)| LetterFrequency {
+            letter: ((b'a' + index as u8) as char).to_string(),
+            count: *count as u32,
+            frequency: round_score(*count as f64 / total as f64),
+        })
+        .collect();
+    letters.sort_by_key(|entry| std::cmp::Reverse(entry.count));
+
+    let chi_squared = if total == 0 {
+        0.0
+    } else {
+        english_chi_squared(&counts, total)
+    };
+    FrequencyReport {
+        total: total as u32,
+        letters,
+        index_of_coincidence: round_score(index_of_coincidence(&counts, total)),
+        chi_squared: round_score(chi_squared),
+    }
+}
@@ -1,17 +1,30 @@
 #![allow(dead_code)]
 use aes::Aes256;
-use base64::{Engine as _, engine::general_purpose};
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::{Engine as _, alphabet, engine::general_purpose};
 use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit, block_padding::Pkcs7};
+use hkdf::Hkdf;
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::{Hmac, Mac, SimpleHmac};
 use md5::Md5;
+use napi::bindgen_prelude::{AbortSignal, AsyncTask, Buffer, Either};
+use napi::{Env, Task};
 use napi_derive::napi;
 use rand::RngCore;
 use rand::rngs::OsRng;
 use rsa::pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey, LineEnding};
 use rsa::{Oaep, RsaPrivateKey, RsaPublicKey};
-use sha2::{Digest, Sha256, Sha512};
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_256};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Keccak256, Sha3_256, Sha3_512, Shake128, Shake256};
+use subtle::ConstantTimeEq;
 
-const ROT13_SHIFT: u8 = 13;
 const ALPHABET_SIZE: u8 = 26;
+const DIGIT_COUNT: u8 = 10;
+const ASCII_ZERO: u8 = b'0';
+const ROT47_FIRST: u8 = b'!';
+const ROT47_SIZE: u8 = 94;
 const ASCII_LOWER_A: u8 = b'a';
 const ASCII_UPPER_A: u8 = b'A';
 const AES_KEY_BYTES: usize = 32;
@@ -22,76 +35,105 @@ const DEFAULT_RSA_BITS: u32 = 4096;
 const MIN_RSA_BITS: u32 = 2048;
 const MAX_RSA_BITS: u32 = 8192;
 const RSA_BITS_STEP: u32 = 256;
+const DEFAULT_SHAKE128_BYTES: u32 = 32;
+const DEFAULT_SHAKE256_BYTES: u32 = 64;
+const MAX_XOF_BYTES: u32 = 1024 * 1024;
+const MAX_DERIVED_BYTES: u32 = 1024 * 1024;
+const BLAKE2B_MAX_BYTES: u32 = 64;
+const BLAKE2S_MAX_BYTES: u32 = 32;
+const DEFAULT_BLAKE3_BYTES: u32 = 32;
+const BLAKE3_KEY_BYTES: usize = 32;
+const BLAKE3_RAYON_THRESHOLD: usize = 128 * 1024;
+const BASE64_URL_NO_PAD: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+const BASE64_NO_PAD: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+const BASE64_MIME_LINE_CHARS: usize = 76;
+const MERKLE_LEAF_PREFIX: u8 = 0x00;
+const MERKLE_NODE_PREFIX: u8 = 0x01;
 const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32_HEX_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const BASE32_CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const BASE32_ZBASE32_ALPHABET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+const BASE32_GROUP_CHARS: usize = 8;
 
-const MORSE_TABLE: &[(char, &str)] = &[
-    ('a', ".-"),
-    ('b', "-..."),
-    ('c', "-.-."),
-    ('d', "-.."),
-    ('e', "."),
-    ('f', "..-."),
-    ('g', "--."),
-    ('h', "...."),
-    ('i', ".."),
-    ('j', ".---"),
-    ('k', "-.-"),
-    ('l', ".-.."),
-    ('m', "--"),
-    ('n', "-."),
-    ('o', "---"),
-    ('p', ".--."),
-    ('q', "--.-"),
-    ('r', ".-."),
-    ('s', "..."),
-    ('t', "-"),
-    ('u', "..-"),
-    ('v', "...-"),
-    ('w', ".--"),
-    ('x', "-..-"),
-    ('y', "-.--"),
-    ('z', "--.."),
-    ('0', "-----"),
-    ('1', ".----"),
-    ('2', "..---"),
-    ('3', "...--"),
-    ('4', "....-"),
-    ('5', "....."),
-    ('6', "-...."),
-    ('7', "--..."),
-    ('8', "---.."),
-    ('9', "----."),
-    ('.', ".-.-.-"),
-    (',', "--..--"),
-    ('?', "..--.."),
-    ('!', "-.-.--"),
-    ('\'', ".----."),
-    ('/', "-..-."),
-    ('(', "-.--."),
-    (')', "-.--.-"),
-    ('&', ".-..."),
-    (':', "---..."),
-    (';', "-.-.-."),
-    ('=', "-...-"),
-    ('+', ".-.-."),
-    ('-', "-....-"),
-    ('_', "..--.-"),
-    ('"', ".-..-."),
-    ('$', "...-..-"),
-    ('@', ".--.-."),
-];
-
-enum BinaryEncoding {
+pub(crate) enum BinaryEncoding {
     Hex,
     Base64,
+    Base64Url,
+    Base64NoPad,
+    Base32,
+    Buffer,
 }
 
+enum Base64Variant {
+    Standard,
+    Url,
+    Mime,
+}
+
+enum Base32Variant {
+    Rfc4648,
+    Hex,
+    Crockford,
+    ZBase32,
+}
+
+enum DecodeMode {
+    Strict,
+    Lenient,
+}
+
+enum RotAlphabet {
+    Letters,
+    Digits,
+    LettersAndDigits,
+    Ascii,
+    Custom(Vec<char>),
+}
+
+pub(crate) enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_256,
+    Sha3_256,
+    Sha3_512,
+    Keccak256,
+}
+
+enum HmacState {
+    Md5(Hmac<Md5>),
+    Sha1(Hmac<Sha1>),
+    Sha224(Hmac<Sha224>),
+    Sha256(Hmac<Sha256>),
+    Sha384(Hmac<Sha384>),
+    Sha512(Hmac<Sha512>),
+    Sha512_256(Hmac<Sha512_256>),
+    Sha3_256(Hmac<Sha3_256>),
+    Sha3_512(Hmac<Sha3_512>),
+    Keccak256(Hmac<Keccak256>),
+}
+
+type ByteInput = Either<String, Buffer>;
+type BinaryOutput = Either<String, Buffer>;
 type Aes256CbcEnc = cbc::Encryptor<Aes256>;
 type Aes256CbcDec = cbc::Decryptor<Aes256>;
 
 #[napi(object)]
 pub struct RsaPacket {
-    pub encoded: String,
+    pub encoded: BinaryOutput,
     #[napi(js_name = "privateKey")]
     pub private_key: String,
     #[napi(js_name = "publicKey")]
@@ -100,17 +142,137 @@ pub struct RsaPacket {
     pub bits: u32,
 }
 
+#[napi(object)]
+pub struct MerkleProof {
+    pub index: u32,
+    pub size: u32,
+    pub hash: String,
+    pub encoding: String,
+    pub siblings: Vec<BinaryOutput>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Base64Options {
+    pub variant: Option<String>,
+    pub padding: Option<bool>,
+    pub mode: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Base32Options {
+    pub variant: Option<String>,
+    pub padding: Option<bool>,
+    pub mode: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct RotOptions {
+    pub alphabet: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Blake2Options {
+    pub length: Option<u32>,
+    pub key: Option<ByteInput>,
+    pub encoding: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct Blake3Options {
+    pub length: Option<u32>,
+    pub key: Option<ByteInput>,
+    pub context: Option<String>,
+    pub encoding: Option<String>,
+}
+
+pub struct DigestTask {
+    algorithm: HashAlgorithm,
+    input: Vec<u8>,
+    encoding: BinaryEncoding,
+}
+
+pub struct Blake3Task {
+    input: Vec<u8>,
+    key: Option<Vec<u8>>,
+    context: Option<String>,
+    length: usize,
+    encoding: BinaryEncoding,
+}
+
+pub struct Pbkdf2Task {
+    algorithm: HashAlgorithm,
+    password: Vec<u8>,
+    salt: Vec<u8>,
+    iterations: u32,
+    length: usize,
+}
+
+pub struct AesEncodeTask {
+    input: Vec<u8>,
+    key: String,
+    encoding: BinaryEncoding,
+}
+
+pub struct AesDecodeTask {
+    encrypted: Vec<u8>,
+    iv: [u8; AES_IV_BYTES],
+    key: String,
+}
+
+pub struct RsaEncodeTask {
+    input: Vec<u8>,
+    encoding: BinaryEncoding,
+    bits: u32,
+}
+
+pub struct RsaDecodeTask {
+    encrypted: Vec<u8>,
+    private_key: String,
+}
+
 #[inline(always)]
 fn invalid_input(message: &str) -> napi::Error {
     napi::Error::from_reason(message.to_string())
 }
 
+#[inline(always)]
+fn input_bytes(input: &ByteInput) -> &[u8] {
+    match input {
+        Either::A(text) => text.as_bytes(),
+        Either::B(buffer) => buffer.as_ref(),
+    }
+}
+
+#[inline(always)]
+fn input_text(input: &ByteInput) -> napi::Result<&str> {
+    match input {
+        Either::A(text) => Ok(text),
+        Either::B(buffer) => std::str::from_utf8(buffer.as_ref())
+            .map_err(|_| invalid_input("Encoded input must be valid UTF-8 text")),
+    }
+}
+
+#[inline(always)]
+fn decoded_text(decoded: Vec<u8>) -> napi::Result<String> {
+    String::from_utf8(decoded)
+        .map_err(|error| invalid_input(&format!("Decoded bytes are not valid UTF-8: {error}")))
+}
+
 #[inline(always)]
 fn binary_encoding_name(encoding: &BinaryEncoding) -> &'static str {
-    if matches!(encoding, BinaryEncoding::Hex) {
-        return "hex";
+    match encoding {
+        BinaryEncoding::Hex => "hex",
+        BinaryEncoding::Base64 => "base64",
+        BinaryEncoding::Base64Url => "base64url",
+        BinaryEncoding::Base64NoPad => "base64-nopad",
+        BinaryEncoding::Base32 => "base32",
+        BinaryEncoding::Buffer => "buffer",
     }
-    "base64"
 }
 
 #[inline(always)]
@@ -127,16 +289,17 @@ fn normalize_binary_encoding(encoding: Option<String>) -> napi::Result<BinaryEnc
 
 #[inline(always)]
 fn normalize_encoding_value(value: &str) -> napi::Result<BinaryEncoding> {
-    let normalized = value.to_ascii_lowercase();
-    if normalized == "hex" || normalized == "base16" {
-        return Ok(BinaryEncoding::Hex);
-    }
-    if normalized == "base64" {
-        return Ok(BinaryEncoding::Base64);
+    match value.to_ascii_lowercase().as_str() {
+        "hex" | "base16" => Ok(BinaryEncoding::Hex),
+        "base64" => Ok(BinaryEncoding::Base64),
+        "base64url" => Ok(BinaryEncoding::Base64Url),
+        "base64-nopad" => Ok(BinaryEncoding::Base64NoPad),
+        "base32" => Ok(BinaryEncoding::Base32),
+        "buffer" => Ok(BinaryEncoding::Buffer),
+        _ => Err(invalid_input(
+            "Encoding must be one of: hex, base16, base64, base64url, base64-nopad, base32, buffer",
+        )),
     }
-    Err(invalid_input(
-        "Encoding must be one of: hex, base16, base64",
-    ))
 }
 
 #[inline(always)]
@@ -152,48 +315,80 @@ fn infer_binary_encoding(input: &str) -> BinaryEncoding {
     if is_hex_text(input) {
         return BinaryEncoding::Hex;
     }
+    if input.contains(['-', '_']) {
+        return BinaryEncoding::Base64Url;
+    }
+    if !input.len().is_multiple_of(4) {
+        return BinaryEncoding::Base64NoPad;
+    }
     BinaryEncoding::Base64
 }
 
 #[inline(always)]
-fn encode_bytes(bytes: &[u8], encoding: &BinaryEncoding) -> String {
-    if matches!(encoding, BinaryEncoding::Hex) {
-        return hex::encode(bytes);
+fn encode_bytes(bytes: &[u8], encoding: &BinaryEncoding) -> BinaryOutput {
+    match encoding {
+        BinaryEncoding::Hex => Either::A(hex::encode(bytes)),
+        BinaryEncoding::Base64 => Either::A(general_purpose::STANDARD.encode(bytes)),
+        BinaryEncoding::Base64Url => Either::A(BASE64_URL_NO_PAD.encode(bytes)),
+        BinaryEncoding::Base64NoPad => Either::A(BASE64_NO_PAD.encode(bytes)),
+        BinaryEncoding::Base32 => {
+            Either::A(base32_encode_bytes(bytes, &Base32Variant::Rfc4648, true))
+        }
+        BinaryEncoding::Buffer => Either::B(bytes.to_vec().into()),
     }
-    general_purpose::STANDARD.encode(bytes)
 }
 
 #[inline(always)]
-fn decode_bytes(input: &str, encoding: &BinaryEncoding) -> napi::Result<Vec<u8>> {
-    if matches!(encoding, BinaryEncoding::Hex) {
-        return hex::decode(input)
-            .map_err(|error| invalid_input(&format!("Invalid hex input: {error}")));
+pub(crate) fn decode_bytes(input: &str, encoding: &BinaryEncoding) -> napi::Result<Vec<u8>> {
+    let base64_error = |error| invalid_input(&format!("Invalid base64 input: {error}"));
+    match encoding {
+        BinaryEncoding::Hex => hex::decode(input)
+            .map_err(|error| invalid_input(&format!("Invalid hex input: {error}"))),
+        BinaryEncoding::Base64 => general_purpose::STANDARD
+            .decode(input.as_bytes())
+            .map_err(base64_error),
+        BinaryEncoding::Base64Url => BASE64_URL_NO_PAD
+            .decode(input.as_bytes())
+            .map_err(base64_error),
+        BinaryEncoding::Base64NoPad => BASE64_NO_PAD.decode(input.as_bytes()).map_err(base64_error),
+        BinaryEncoding::Base32 => {
+            base32_decode_bytes(input, &Base32Variant::Rfc4648, true, &DecodeMode::Lenient)
+        }
+        BinaryEncoding::Buffer => Err(invalid_input("Buffer encoding requires Buffer input")),
+    }
+}
+
+#[inline(always)]
+fn decode_input(input: &ByteInput, encoding: &BinaryEncoding) -> napi::Result<Vec<u8>> {
+    match input {
+        Either::A(text) => decode_bytes(text, encoding),
+        Either::B(buffer) => Ok(buffer.to_vec()),
     }
-    general_purpose::STANDARD
-        .decode(input.as_bytes())
-        .map_err(|error| invalid_input(&format!("Invalid base64 input: {error}")))
 }
 
 #[inline(always)]
 fn decode_bytes_with_optional_encoding(
-    input: &str,
+    input: &ByteInput,
     encoding: Option<String>,
 ) -> napi::Result<Vec<u8>> {
     if let Some(value) = encoding {
         let normalized = normalize_encoding_value(&value)?;
-        return decode_bytes(input, &normalized);
+        return decode_input(input, &normalized);
     }
-    let inferred = infer_binary_encoding(input);
-    decode_bytes(input, &inferred)
+    let Either::A(text) = input else {
+        return decode_input(input, &BinaryEncoding::Buffer);
+    };
+    let inferred = infer_binary_encoding(text);
+    decode_bytes(text, &inferred)
 }
 
 #[inline(always)]
-fn digest_array<H, const N: usize>(input: &str) -> [u8; N]
+fn digest_array<H, const N: usize>(input: &[u8]) -> [u8; N]
 where
     H: Digest + Default,
 {
     let mut hasher = H::new();
-    hasher.update(input.as_bytes());
+    Digest::update(&mut hasher, input);
     let digest = hasher.finalize();
     let mut output = [0_u8; N];
     output.copy_from_slice(&digest);
@@ -201,349 +396,1746 @@ where
 }
 
 #[inline(always)]
-fn digest_md5(input: &str) -> [u8; 16] {
+fn digest_md5(input: &[u8]) -> [u8; 16] {
     digest_array::<Md5, 16>(input)
 }
 
 #[inline(always)]
-fn digest_sha256(input: &str) -> [u8; 32] {
+fn digest_sha256(input: &[u8]) -> [u8; 32] {
     digest_array::<Sha256, 32>(input)
 }
 
 #[inline(always)]
-fn digest_sha512(input: &str) -> [u8; 64] {
+fn digest_sha512(input: &[u8]) -> [u8; 64] {
     digest_array::<Sha512, 64>(input)
 }
 
 #[inline(always)]
-fn normalize_rot_shift(shift: Option<u8>) -> u8 {
-    let normalized = shift.unwrap_or(ROT13_SHIFT) % ALPHABET_SIZE;
-    if normalized == 0 {
-        return ROT13_SHIFT;
-    }
-    normalized
+fn digest_sha1(input: &[u8]) -> [u8; 20] {
+    digest_array::<Sha1, 20>(input)
 }
 
 #[inline(always)]
-fn rotate_letter(byte: u8, base: u8, shift: u8) -> u8 {
-    ((byte - base + shift) % ALPHABET_SIZE) + base
+fn digest_sha224(input: &[u8]) -> [u8; 28] {
+    digest_array::<Sha224, 28>(input)
 }
 
 #[inline(always)]
-fn rotate_ascii_char(ch: char, shift: u8) -> char {
-    if ch.is_ascii_lowercase() {
-        return rotate_letter(ch as u8, ASCII_LOWER_A, shift) as char;
-    }
-    if ch.is_ascii_uppercase() {
-        return rotate_letter(ch as u8, ASCII_UPPER_A, shift) as char;
-    }
-    ch
+fn digest_sha384(input: &[u8]) -> [u8; 48] {
+    digest_array::<Sha384, 48>(input)
 }
 
 #[inline(always)]
-fn base32_value(ch: u8) -> Option<u8> {
-    if ch.is_ascii_uppercase() {
-        return Some(ch - b'A');
-    }
-    if (b'2'..=b'7').contains(&ch) {
-        return Some(ch - b'2' + 26);
-    }
-    None
+fn digest_sha512_256(input: &[u8]) -> [u8; 32] {
+    digest_array::<Sha512_256, 32>(input)
 }
 
 #[inline(always)]
-fn base32_encode_bytes(input: &[u8]) -> String {
-    if input.is_empty() {
-        return String::new();
-    }
-
-    let mut output = String::new();
-    let mut buffer: u16 = 0;
-    let mut bits: u8 = 0;
-    for byte in input {
-        buffer = (buffer << 8) | (*byte as u16);
-        bits += 8;
-        while bits >= 5 {
-            let index = ((buffer >> (bits - 5)) & 0x1f) as usize;
-            output.push(BASE32_ALPHABET[index] as char);
-            bits -= 5;
-        }
-    }
+fn digest_sha3_256(input: &[u8]) -> [u8; 32] {
+    digest_array::<Sha3_256, 32>(input)
+}
 
-    if bits > 0 {
-        let index = ((buffer << (5 - bits)) & 0x1f) as usize;
-        output.push(BASE32_ALPHABET[index] as char);
-    }
-    while !output.len().is_multiple_of(8) {
-        output.push('=');
-    }
-    output
+#[inline(always)]
+fn digest_sha3_512(input: &[u8]) -> [u8; 64] {
+    digest_array::<Sha3_512, 64>(input)
 }
 
 #[inline(always)]
-fn base32_decode_bytes(input: &str) -> napi::Result<Vec<u8>> {
-    let mut output = Vec::new();
-    let mut buffer: u32 = 0;
-    let mut bits: u8 = 0;
-    for raw in input.bytes() {
-        if raw.is_ascii_whitespace() {
-            continue;
-        }
-        if raw == b'=' {
-            break;
-        }
+fn digest_keccak256(input: &[u8]) -> [u8; 32] {
+    digest_array::<Keccak256, 32>(input)
+}
 
-        let upper = raw.to_ascii_uppercase();
-        let Some(value) = base32_value(upper) else {
-            return Err(invalid_input("Invalid base32 input"));
-        };
-        buffer = (buffer << 5) | (value as u32);
-        bits += 5;
-        while bits >= 8 {
-            let byte = ((buffer >> (bits - 8)) & 0xff) as u8;
-            output.push(byte);
-            bits -= 8;
-        }
+#[inline(always)]
+pub(crate) fn digest_with(algorithm: &HashAlgorithm, input: &[u8]) -> Vec<u8> {
+    match algorithm {
+        HashAlgorithm::Md5 => digest_md5(input).to_vec(),
+        HashAlgorithm::Sha1 => digest_sha1(input).to_vec(),
+        HashAlgorithm::Sha224 => digest_sha224(input).to_vec(),
+        HashAlgorithm::Sha256 => digest_sha256(input).to_vec(),
+        HashAlgorithm::Sha384 => digest_sha384(input).to_vec(),
+        HashAlgorithm::Sha512 => digest_sha512(input).to_vec(),
+        HashAlgorithm::Sha512_256 => digest_sha512_256(input).to_vec(),
+        HashAlgorithm::Sha3_256 => digest_sha3_256(input).to_vec(),
+        HashAlgorithm::Sha3_512 => digest_sha3_512(input).to_vec(),
+        HashAlgorithm::Keccak256 => digest_keccak256(input).to_vec(),
     }
-    Ok(output)
 }
 
 #[inline(always)]
-fn morse_code_for(ch: char) -> Option<&'static str> {
-    let normalized = ch.to_ascii_lowercase();
-    MORSE_TABLE
-        .iter()
-        .find(|(symbol, _)| *symbol == normalized)
-        .map(|(_, code)| *code)
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    let length = left.len().max(right.len());
+    let mut difference = 0_u8;
+    for index in 0..length {
+        let a = left.get(index).copied().unwrap_or(0);
+        let b = right.get(index).copied().unwrap_or(0);
+        difference |= a ^ b;
+    }
+    let same_length = (left.len() as u64).ct_eq(&(right.len() as u64));
+    let same_bytes = difference.ct_eq(&0);
+    bool::from(same_length & same_bytes)
 }
 
 #[inline(always)]
-fn morse_char_for(code: &str) -> Option<char> {
-    MORSE_TABLE
-        .iter()
-        .find(|(_, symbol)| *symbol == code)
-        .map(|(ch, _)| *ch)
+fn normalize_xof_length(length: Option<u32>, default_length: u32) -> napi::Result<usize> {
+    let value = length.unwrap_or(default_length);
+    if !(1..=MAX_XOF_BYTES).contains(&value) {
+        return Err(invalid_input(&format!(
+            "Output length must be in range 1..={MAX_XOF_BYTES}"
+        )));
+    }
+    Ok(value as usize)
 }
 
 #[inline(always)]
-fn should_insert_space(output: &str) -> bool {
-    !output.is_empty() && !output.ends_with(' ')
+fn digest_xof<H>(input: &[u8], length: usize) -> Vec<u8>
+where
+    H: ExtendableOutput + Update + Default,
+{
+    let mut hasher = H::default();
+    hasher.update(input);
+    let mut reader = hasher.finalize_xof();
+    let mut output = vec![0_u8; length];
+    reader.read(&mut output);
+    output
 }
 
 #[inline(always)]
-fn normalize_aes_iv(iv_hex: &str) -> napi::Result<[u8; AES_IV_BYTES]> {
-    if iv_hex.len() != AES_IV_HEX_CHARS {
-        return Err(invalid_input(
-            "AES IV must be 16 bytes encoded as 32 hex characters",
-        ));
-    }
-    let decoded = hex::decode(iv_hex).map_err(|_| invalid_input("AES IV must be valid hex"))?;
-    if decoded.len() != AES_IV_BYTES {
-        return Err(invalid_input(
-            "AES IV must be 16 bytes encoded as 32 hex characters",
-        ));
+fn normalize_blake_length(
+    length: Option<u32>,
+    default_length: u32,
+    max_length: u32,
+) -> napi::Result<usize> {
+    let value = length.unwrap_or(default_length);
+    if !(1..=max_length).contains(&value) {
+        return Err(invalid_input(&format!(
+            "Output length must be in range 1..={max_length}"
+        )));
     }
-
-    let mut iv = [0_u8; AES_IV_BYTES];
-    iv.copy_from_slice(&decoded);
-    Ok(iv)
+    Ok(value as usize)
 }
 
 #[inline(always)]
-fn generate_aes_iv_hex() -> String {
-    let mut iv = [0_u8; AES_IV_BYTES];
-    let mut rng = OsRng;
-    rng.fill_bytes(&mut iv);
-    hex::encode(iv)
+fn digest_blake2b(input: &[u8], key: &[u8], length: usize) -> napi::Result<Vec<u8>> {
+    if key.len() > blake2b_simd::KEYBYTES {
+        return Err(invalid_input("BLAKE2b key must be at most 64 bytes"));
+    }
+    let digest = blake2b_simd::Params::new()
+        .hash_length(length)
+        .key(key)
+        .hash(input);
+    Ok(digest.as_bytes().to_vec())
 }
 
 #[inline(always)]
-fn derive_aes_key(key: &str) -> [u8; AES_KEY_BYTES] {
-    digest_sha256(key)
+fn digest_blake2s(input: &[u8], key: &[u8], length: usize) -> napi::Result<Vec<u8>> {
+    if key.len() > blake2s_simd::KEYBYTES {
+        return Err(invalid_input("BLAKE2s key must be at most 32 bytes"));
+    }
+    let digest = blake2s_simd::Params::new()
+        .hash_length(length)
+        .key(key)
+        .hash(input);
+    Ok(digest.as_bytes().to_vec())
 }
 
 #[inline(always)]
-fn aes_encrypt(
-    input: &[u8],
-    key: &[u8; AES_KEY_BYTES],
-    iv: &[u8; AES_IV_BYTES],
-) -> napi::Result<Vec<u8>> {
-    let cipher = Aes256CbcEnc::new_from_slices(key, iv)
-        .map_err(|_| invalid_input("AES key or IV is invalid"))?;
-    let mut buffer = vec![0_u8; input.len() + AES_BLOCK_BYTES];
-    buffer[..input.len()].copy_from_slice(input);
-    let encrypted = cipher
-        .encrypt_padded_mut::<Pkcs7>(&mut buffer, input.len())
-        .map_err(|_| invalid_input("AES encryption failed"))?;
-    Ok(encrypted.to_vec())
+fn blake3_hasher(key: Option<&[u8]>, context: Option<&str>) -> napi::Result<blake3::Hasher> {
+    match (key, context) {
+        (Some(_), Some(_)) => Err(invalid_input(
+            "BLAKE3 key and context are mutually exclusive",
+        )),
+        (Some(key), None) => {
+            let key: &[u8; BLAKE3_KEY_BYTES] = key
+                .try_into()
+                .map_err(|_| invalid_input("BLAKE3 key must be exactly 32 bytes"))?;
+            Ok(blake3::Hasher::new_keyed(key))
+        }
+        (None, Some(context)) => Ok(blake3::Hasher::new_derive_key(context)),
+        (None, None) => Ok(blake3::Hasher::new()),
+    }
 }
 
 #[inline(always)]
-fn aes_decrypt(
+fn digest_blake3(
     input: &[u8],
-    key: &[u8; AES_KEY_BYTES],
-    iv: &[u8; AES_IV_BYTES],
+    key: Option<&[u8]>,
+    context: Option<&str>,
+    length: usize,
 ) -> napi::Result<Vec<u8>> {
-    let cipher = Aes256CbcDec::new_from_slices(key, iv)
-        .map_err(|_| invalid_input("AES key or IV is invalid"))?;
-    let mut buffer = input.to_vec();
-    let decrypted = cipher
-        .decrypt_padded_mut::<Pkcs7>(&mut buffer)
-        .map_err(|_| invalid_input("AES decryption failed"))?;
-    Ok(decrypted.to_vec())
+    let mut hasher = blake3_hasher(key, context)?;
+    if input.len() >= BLAKE3_RAYON_THRESHOLD {
+        hasher.update_rayon(input);
+    } else {
+        hasher.update(input);
+    }
+    let mut output = vec![0_u8; length];
+    hasher.finalize_xof().fill(&mut output);
+    Ok(output)
 }
 
 #[inline(always)]
-fn normalize_rsa_bits(bits: Option<u32>) -> napi::Result<u32> {
-    let value = bits.unwrap_or(DEFAULT_RSA_BITS);
-    if !(MIN_RSA_BITS..=MAX_RSA_BITS).contains(&value) {
-        return Err(invalid_input("RSA bits must be in range 2048..=8192"));
+pub(crate) fn normalize_hash_algorithm(value: &str) -> napi::Result<HashAlgorithm> {
+    match value.to_ascii_lowercase().as_str() {
+        "md5" => Ok(HashAlgorithm::Md5),
+        "sha1" => Ok(HashAlgorithm::Sha1),
+        "sha224" => Ok(HashAlgorithm::Sha224),
+        "sha256" => Ok(HashAlgorithm::Sha256),
+        "sha384" => Ok(HashAlgorithm::Sha384),
+        "sha512" => Ok(HashAlgorithm::Sha512),
+        "sha512_256" => Ok(HashAlgorithm::Sha512_256),
+        "sha3_256" => Ok(HashAlgorithm::Sha3_256),
+        "sha3_512" => Ok(HashAlgorithm::Sha3_512),
+        "keccak256" => Ok(HashAlgorithm::Keccak256),
+        _ => Err(invalid_input(
+            "Hash algorithm must be one of: md5, sha1, sha224, sha256, sha384, sha512, sha512_256, sha3_256, sha3_512, keccak256",
+        )),
     }
-    if !value.is_multiple_of(RSA_BITS_STEP) {
-        return Err(invalid_input("RSA bits must be a multiple of 256"));
+}
+
+#[inline(always)]
+pub(crate) fn hash_algorithm_name(algorithm: &HashAlgorithm) -> &'static str {
+    match algorithm {
+        HashAlgorithm::Md5 => "md5",
+        HashAlgorithm::Sha1 => "sha1",
+        HashAlgorithm::Sha224 => "sha224",
+        HashAlgorithm::Sha256 => "sha256",
+        HashAlgorithm::Sha384 => "sha384",
+        HashAlgorithm::Sha512 => "sha512",
+        HashAlgorithm::Sha512_256 => "sha512_256",
+        HashAlgorithm::Sha3_256 => "sha3_256",
+        HashAlgorithm::Sha3_512 => "sha3_512",
+        HashAlgorithm::Keccak256 => "keccak256",
     }
-    Ok(value)
 }
 
 #[inline(always)]
-fn normalize_rsa_encoding(encoding: Option<String>) -> napi::Result<BinaryEncoding> {
-    normalize_binary_encoding(encoding)
+fn hmac_new<M>(key: &[u8]) -> M
+where
+    M: Mac + hmac::digest::KeyInit,
+{
+    <M as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length")
 }
 
 #[inline(always)]
-fn rsa_generate_keys(bits: u32) -> napi::Result<(RsaPrivateKey, RsaPublicKey)> {
-    let mut rng = OsRng;
-    let private = RsaPrivateKey::new(&mut rng, bits as usize)
-        .map_err(|error| invalid_input(&format!("RSA key generation failed: {error}")))?;
-    let public = RsaPublicKey::from(&private);
-    Ok((private, public))
+fn hmac_state(algorithm: &HashAlgorithm, key: &[u8]) -> HmacState {
+    match algorithm {
+        HashAlgorithm::Md5 => HmacState::Md5(hmac_new(key)),
+        HashAlgorithm::Sha1 => HmacState::Sha1(hmac_new(key)),
+        HashAlgorithm::Sha224 => HmacState::Sha224(hmac_new(key)),
+        HashAlgorithm::Sha256 => HmacState::Sha256(hmac_new(key)),
+        HashAlgorithm::Sha384 => HmacState::Sha384(hmac_new(key)),
+        HashAlgorithm::Sha512 => HmacState::Sha512(hmac_new(key)),
+        HashAlgorithm::Sha512_256 => HmacState::Sha512_256(hmac_new(key)),
+        HashAlgorithm::Sha3_256 => HmacState::Sha3_256(hmac_new(key)),
+        HashAlgorithm::Sha3_512 => HmacState::Sha3_512(hmac_new(key)),
+        HashAlgorithm::Keccak256 => HmacState::Keccak256(hmac_new(key)),
+    }
 }
 
 #[inline(always)]
-fn rsa_private_to_pem(private: &RsaPrivateKey) -> napi::Result<String> {
-    private
-        .to_pkcs8_pem(LineEnding::LF)
-        .map(|pem| pem.to_string())
-        .map_err(|error| invalid_input(&format!("RSA private key export failed: {error}")))
+fn hmac_update(state: &mut HmacState, input: &[u8]) {
+    match state {
+        HmacState::Md5(mac) => Mac::update(mac, input),
+        HmacState::Sha1(mac) => Mac::update(mac, input),
+        HmacState::Sha224(mac) => Mac::update(mac, input),
+        HmacState::Sha256(mac) => Mac::update(mac, input),
+        HmacState::Sha384(mac) => Mac::update(mac, input),
+        HmacState::Sha512(mac) => Mac::update(mac, input),
+        HmacState::Sha512_256(mac) => Mac::update(mac, input),
+        HmacState::Sha3_256(mac) => Mac::update(mac, input),
+        HmacState::Sha3_512(mac) => Mac::update(mac, input),
+        HmacState::Keccak256(mac) => Mac::update(mac, input),
+    }
 }
 
 #[inline(always)]
-fn rsa_public_to_pem(public: &RsaPublicKey) -> napi::Result<String> {
-    public
-        .to_public_key_pem(LineEnding::LF)
-        .map(|pem| pem.to_string())
-        .map_err(|error| invalid_input(&format!("RSA public key export failed: {error}")))
+fn hmac_finalize(state: HmacState) -> Vec<u8> {
+    match state {
+        HmacState::Md5(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha1(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha224(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha256(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha384(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha512(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha512_256(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha3_256(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Sha3_512(mac) => mac.finalize().into_bytes().to_vec(),
+        HmacState::Keccak256(mac) => mac.finalize().into_bytes().to_vec(),
+    }
 }
 
 #[inline(always)]
-fn rsa_private_from_pem(pem: &str) -> napi::Result<RsaPrivateKey> {
-    RsaPrivateKey::from_pkcs8_pem(pem)
-        .map_err(|error| invalid_input(&format!("RSA private key is invalid: {error}")))
+fn hmac_verify(state: HmacState, expected: &[u8]) -> bool {
+    match state {
+        HmacState::Md5(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha1(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha224(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha256(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha384(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha512(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha512_256(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha3_256(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Sha3_512(mac) => mac.verify_slice(expected).is_ok(),
+        HmacState::Keccak256(mac) => mac.verify_slice(expected).is_ok(),
+    }
 }
 
 #[inline(always)]
-fn rsa_encrypt(public: &RsaPublicKey, input: &[u8]) -> napi::Result<Vec<u8>> {
-    let mut rng = OsRng;
-    public
-        .encrypt(&mut rng, Oaep::new::<Sha256>(), input)
-        .map_err(|error| invalid_input(&format!("RSA encryption failed: {error}")))
+fn normalize_derived_length(length: u32) -> napi::Result<usize> {
+    if !(1..=MAX_DERIVED_BYTES).contains(&length) {
+        return Err(invalid_input(&format!(
+            "Output length must be in range 1..={MAX_DERIVED_BYTES}"
+        )));
+    }
+    Ok(length as usize)
 }
 
 #[inline(always)]
-fn rsa_decrypt(private: &RsaPrivateKey, input: &[u8]) -> napi::Result<Vec<u8>> {
-    private
-        .decrypt(Oaep::new::<Sha256>(), input)
-        .map_err(|error| invalid_input(&format!("RSA decryption failed: {error}")))
+fn normalize_kdf_hash(hash: Option<String>) -> napi::Result<HashAlgorithm> {
+    let value = hash.unwrap_or_else(|| "sha256".to_string());
+    normalize_hash_algorithm(&value)
 }
 
-#[napi(namespace = "base64", js_name = "encode")]
-pub fn base64_encode(input: String) -> String {
-    general_purpose::STANDARD.encode(input.as_bytes())
+#[inline(always)]
+fn hkdf_derive<H>(ikm: &[u8], salt: &[u8], info: &[u8], length: usize) -> napi::Result<Vec<u8>>
+where
+    H: Digest + BlockSizeUser + Clone,
+{
+    let hkdf = Hkdf::<H, SimpleHmac<H>>::new(Some(salt), ikm);
+    let mut output = vec![0_u8; length];
+    hkdf.expand(info, &mut output)
+        .map_err(|_| invalid_input("HKDF output length is too large for the selected hash"))?;
+    Ok(output)
 }
 
-#[napi(namespace = "base64", js_name = "decode")]
-pub fn base64_decode(input: String) -> napi::Result<String> {
-    let decoded = general_purpose::STANDARD
-        .decode(input.as_bytes())
-        .map_err(|error| invalid_input(&format!("Invalid base64 input: {error}")))?;
-    String::from_utf8(decoded)
-        .map_err(|error| invalid_input(&format!("Decoded bytes are not valid UTF-8: {error}")))
+#[inline(always)]
+fn hkdf_with(
+    algorithm: &HashAlgorithm,
+    ikm: &[u8],
+    salt: &[u8],
+    info: &[u8],
+    length: usize,
+) -> napi::Result<Vec<u8>> {
+    match algorithm {
+        HashAlgorithm::Md5 => hkdf_derive::<Md5>(ikm, salt, info, length),
+        HashAlgorithm::Sha1 => hkdf_derive::<Sha1>(ikm, salt, info, length),
+        HashAlgorithm::Sha224 => hkdf_derive::<Sha224>(ikm, salt, info, length),
+        HashAlgorithm::Sha256 => hkdf_derive::<Sha256>(ikm, salt, info, length),
+        HashAlgorithm::Sha384 => hkdf_derive::<Sha384>(ikm, salt, info, length),
+        HashAlgorithm::Sha512 => hkdf_derive::<Sha512>(ikm, salt, info, length),
+        HashAlgorithm::Sha512_256 => hkdf_derive::<Sha512_256>(ikm, salt, info, length),
+        HashAlgorithm::Sha3_256 => hkdf_derive::<Sha3_256>(ikm, salt, info, length),
+        HashAlgorithm::Sha3_512 => hkdf_derive::<Sha3_512>(ikm, salt, info, length),
+        HashAlgorithm::Keccak256 => hkdf_derive::<Keccak256>(ikm, salt, info, length),
+    }
 }
 
-#[napi(namespace = "base16", js_name = "encode")]
-pub fn base16_encode(input: String) -> String {
-    hex::encode(input.as_bytes())
+#[inline(always)]
+fn pbkdf2_derive<H>(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> Vec<u8>
+where
+    H: Digest + BlockSizeUser + Clone + Sync,
+{
+    let mut output = vec![0_u8; length];
+    pbkdf2::pbkdf2::<SimpleHmac<H>>(password, salt, iterations, &mut output)
+        .expect("HMAC accepts keys of any length");
+    output
 }
 
-#[napi(namespace = "base16", js_name = "decode")]
-pub fn base16_decode(input: String) -> napi::Result<String> {
-    let decoded = hex::decode(input)
-        .map_err(|error| invalid_input(&format!("Invalid hex input: {error}")))?;
-    String::from_utf8(decoded)
-        .map_err(|error| invalid_input(&format!("Decoded bytes are not valid UTF-8: {error}")))
+#[inline(always)]
+fn pbkdf2_with(
+    algorithm: &HashAlgorithm,
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    length: usize,
+) -> Vec<u8> {
+    match algorithm {
+        HashAlgorithm::Md5 => pbkdf2_derive::<Md5>(password, salt, iterations, length),
+        HashAlgorithm::Sha1 => pbkdf2_derive::<Sha1>(password, salt, iterations, length),
+        HashAlgorithm::Sha224 => pbkdf2_derive::<Sha224>(password, salt, iterations, length),
+        HashAlgorithm::Sha256 => pbkdf2_derive::<Sha256>(password, salt, iterations, length),
+        HashAlgorithm::Sha384 => pbkdf2_derive::<Sha384>(password, salt, iterations, length),
+        HashAlgorithm::Sha512 => pbkdf2_derive::<Sha512>(password, salt, iterations, length),
+        HashAlgorithm::Sha512_256 => {
+            pbkdf2_derive::<Sha512_256>(password, salt, iterations, length)
+        }
+        HashAlgorithm::Sha3_256 => pbkdf2_derive::<Sha3_256>(password, salt, iterations, length),
+        HashAlgorithm::Sha3_512 => pbkdf2_derive::<Sha3_512>(password, salt, iterations, length),
+        HashAlgorithm::Keccak256 => pbkdf2_derive::<Keccak256>(password, salt, iterations, length),
+    }
+}
+
+#[inline(always)]
+fn merkle_leaf_hash(algorithm: &HashAlgorithm, leaf: &[u8]) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(leaf.len() + 1);
+    buffer.push(MERKLE_LEAF_PREFIX);
+    buffer.extend_from_slice(leaf);
+    digest_with(algorithm, &buffer)
+}
+
+#[inline(always)]
+fn merkle_node_hash(algorithm: &HashAlgorithm, left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(left.len() + right.len() + 1);
+    buffer.push(MERKLE_NODE_PREFIX);
+    buffer.extend_from_slice(left);
+    buffer.extend_from_slice(right);
+    digest_with(algorithm, &buffer)
+}
+
+#[inline(always)]
+fn merkle_split(size: usize) -> usize {
+    let mut split = 1;
+    while split << 1 < size {
+        split <<= 1;
+    }
+    split
+}
+
+fn merkle_subtree_root(algorithm: &HashAlgorithm, hashes: &[Vec<u8>]) -> Vec<u8> {
+    if hashes.len() == 1 {
+        return hashes[0].clone();
+    }
+    let split = merkle_split(hashes.len());
+    let left = merkle_subtree_root(algorithm, &hashes[..split]);
+    let right = merkle_subtree_root(algorithm, &hashes[split..]);
+    merkle_node_hash(algorithm, &left, &right)
+}
+
+fn merkle_audit_path(
+    algorithm: &HashAlgorithm,
+    hashes: &[Vec<u8>],
+    index: usize,
+    path: &mut Vec<Vec<u8>>,
+) {
+    if hashes.len() <= 1 {
+        return;
+    }
+    let split = merkle_split(hashes.len());
+    if index < split {
+        merkle_audit_path(algorithm, &hashes[..split], index, path);
+        path.push(merkle_subtree_root(algorithm, &hashes[split..]));
+        return;
+    }
+    merkle_audit_path(algorithm, &hashes[split..], index - split, path);
+    path.push(merkle_subtree_root(algorithm, &hashes[..split]));
+}
+
+#[inline(always)]
+fn merkle_leaf_hashes(algorithm: &HashAlgorithm, leaves: &[ByteInput]) -> Vec<Vec<u8>> {
+    leaves
+        .iter()
+        .map(|leaf| merkle_leaf_hash(algorithm, input_bytes(leaf)))
+        .collect()
+}
+
+#[inline(always)]
+fn merkle_root_bytes(algorithm: &HashAlgorithm, leaves: &[ByteInput]) -> Vec<u8> {
+    if leaves.is_empty() {
+        return digest_with(algorithm, &[]);
+    }
+    let hashes = merkle_leaf_hashes(algorithm, leaves);
+    merkle_subtree_root(algorithm, &hashes)
+}
+
+#[inline(always)]
+fn merkle_root_from_path(
+    algorithm: &HashAlgorithm,
+    leaf_hash: Vec<u8>,
+    index: u64,
+    size: u64,
+    path: &[Vec<u8>],
+) -> Option<Vec<u8>> {
+    if index >= size {
+        return None;
+    }
+    let mut node = index;
+    let mut last = size - 1;
+    let mut hash = leaf_hash;
+    for sibling in path {
+        if last == 0 {
+            return None;
+        }
+        if node & 1 == 1 || node == last {
+            hash = merkle_node_hash(algorithm, sibling, &hash);
+            if node & 1 == 0 {
+                while node & 1 == 0 && node != 0 {
+                    node >>= 1;
+                    last >>= 1;
+                }
+            }
+        } else {
+            hash = merkle_node_hash(algorithm, &hash, sibling);
+        }
+        node >>= 1;
+        last >>= 1;
+    }
+    if last != 0 {
+        return None;
+    }
+    Some(hash)
+}
+
+#[inline(always)]
+fn normalize_rot_alphabet(options: Option<RotOptions>) -> napi::Result<RotAlphabet> {
+    let Some(alphabet) = options.unwrap_or_default().alphabet else {
+        return Ok(RotAlphabet::Letters);
+    };
+    match alphabet.to_ascii_lowercase().as_str() {
+        "rot13" => return Ok(RotAlphabet::Letters),
+        "rot5" => return Ok(RotAlphabet::Digits),
+        "rot18" => return Ok(RotAlphabet::LettersAndDigits),
+        "rot47" => return Ok(RotAlphabet::Ascii),
+        _ => {}
+    }
+
+    let chars: Vec<char> = alphabet.chars().collect();
+    if chars.len() < 2 {
+        return Err(invalid_input(
+            "Rot alphabet must be rot13, rot5, rot18, rot47 or at least 2 characters",
+        ));
+    }
+    for (index, ch) in chars.iter().enumerate() {
+        if chars[..index].contains(ch) {
+            return Err(invalid_input(&format!(
+                "Rot alphabet must not contain duplicate characters: {ch:?}"
+            )));
+        }
+    }
+    Ok(RotAlphabet::Custom(chars))
+}
+
+#[inline(always)]
+fn normalize_rot_shift(shift: Option<i64>, size: usize) -> usize {
+    match shift {
+        Some(value) => value.rem_euclid(size as i64) as usize,
+        None => size / 2,
+    }
+}
+
+#[inline(always)]
+fn rotate_in_range(byte: u8, base: u8, size: u8, shift: u8) -> u8 {
+    ((byte - base + shift) % size) + base
+}
+
+#[inline(always)]
+fn rotate_letter(byte: u8, base: u8, shift: u8) -> u8 {
+    rotate_in_range(byte, base, ALPHABET_SIZE, shift)
+}
+
+#[inline(always)]
+fn rotate_digit_char(ch: char, shift: u8) -> char {
+    if ch.is_ascii_digit() {
+        return rotate_in_range(ch as u8, ASCII_ZERO, DIGIT_COUNT, shift) as char;
+    }
+    ch
+}
+
+#[inline(always)]
+fn rotate_rot47_char(ch: char, shift: u8) -> char {
+    if ch.is_ascii_graphic() {
+        return rotate_in_range(ch as u8, ROT47_FIRST, ROT47_SIZE, shift) as char;
+    }
+    ch
+}
+
+#[inline(always)]
+pub(crate) fn rotate_ascii_char(ch: char, shift: u8) -> char {
+    if ch.is_ascii_lowercase() {
+        return rotate_letter(ch as u8, ASCII_LOWER_A, shift) as char;
+    }
+    if ch.is_ascii_uppercase() {
+        return rotate_letter(ch as u8, ASCII_UPPER_A, shift) as char;
+    }
+    ch
+}
+
+#[inline(always)]
+fn normalize_base64_variant(variant: Option<String>) -> napi::Result<Base64Variant> {
+    let Some(value) = variant else {
+        return Ok(Base64Variant::Standard);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "standard" => Ok(Base64Variant::Standard),
+        "url" | "base64url" => Ok(Base64Variant::Url),
+        "mime" => Ok(Base64Variant::Mime),
+        _ => Err(invalid_input(
+            "Base64 variant must be one of: standard, url, mime",
+        )),
+    }
+}
+
+#[inline(always)]
+fn normalize_decode_mode(mode: Option<String>) -> napi::Result<DecodeMode> {
+    let Some(value) = mode else {
+        return Ok(DecodeMode::Strict);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "strict" => Ok(DecodeMode::Strict),
+        "lenient" => Ok(DecodeMode::Lenient),
+        _ => Err(invalid_input("Decode mode must be one of: strict, lenient")),
+    }
+}
+
+#[inline(always)]
+fn base64_engine(variant: &Base64Variant, padding: bool, mode: &DecodeMode) -> GeneralPurpose {
+    let alphabet = match variant {
+        Base64Variant::Url => &alphabet::URL_SAFE,
+        Base64Variant::Standard | Base64Variant::Mime => &alphabet::STANDARD,
+    };
+    let lenient = matches!(mode, DecodeMode::Lenient);
+    let padding_mode = if lenient {
+        DecodePaddingMode::Indifferent
+    } else if padding {
+        DecodePaddingMode::RequireCanonical
+    } else {
+        DecodePaddingMode::RequireNone
+    };
+    GeneralPurpose::new(
+        alphabet,
+        GeneralPurposeConfig::new()
+            .with_encode_padding(padding)
+            .with_decode_padding_mode(padding_mode)
+            .with_decode_allow_trailing_bits(lenient),
+    )
+}
+
+#[inline(always)]
+fn wrap_base64_lines(encoded: &str) -> String {
+    let mut output = String::with_capacity(encoded.len() + encoded.len() / 38);
+    for (index, line) in encoded
+        .as_bytes()
+        .chunks(BASE64_MIME_LINE_CHARS)
+        .enumerate()
+    {
+        if index > 0 {
+            output.push_str("\r\n");
+        }
+        output.push_str(std::str::from_utf8(line).unwrap_or_default());
+    }
+    output
+}
+
+#[inline(always)]
+fn unwrap_base64_lines(input: &str) -> napi::Result<String> {
+    let mut output = String::with_capacity(input.len());
+    for line in input.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.len() > BASE64_MIME_LINE_CHARS {
+            return Err(invalid_input(
+                "Invalid base64 input: MIME lines must be at most 76 characters",
+            ));
+        }
+        output.push_str(line);
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn normalize_lenient_base64(input: &str, variant: &Base64Variant) -> String {
+    input
+        .chars()
+        .filter(|ch| !ch.is_ascii_whitespace())
+        .map(|ch| match (variant, ch) {
+            (Base64Variant::Url, '+') => '-',
+            (Base64Variant::Url, '/') => '_',
+            (Base64Variant::Standard | Base64Variant::Mime, '-') => '+',
+            (Base64Variant::Standard | Base64Variant::Mime, '_') => '/',
+            _ => ch,
+        })
+        .collect()
+}
+
+#[inline(always)]
+fn base64_encode_with(input: &[u8], options: Base64Options) -> napi::Result<String> {
+    let variant = normalize_base64_variant(options.variant)?;
+    let padding = options.padding.unwrap_or(true);
+    let engine = base64_engine(&variant, padding, &DecodeMode::Strict);
+    let encoded = engine.encode(input);
+    if matches!(variant, Base64Variant::Mime) {
+        return Ok(wrap_base64_lines(&encoded));
+    }
+    Ok(encoded)
+}
+
+#[inline(always)]
+fn base64_decode_with(input: &str, options: Base64Options) -> napi::Result<Vec<u8>> {
+    let variant = normalize_base64_variant(options.variant)?;
+    let mode = normalize_decode_mode(options.mode)?;
+    let padding = options.padding.unwrap_or(true);
+    let engine = base64_engine(&variant, padding, &mode);
+    let normalized = match (&mode, &variant) {
+        (DecodeMode::Lenient, _) => normalize_lenient_base64(input, &variant),
+        (DecodeMode::Strict, Base64Variant::Mime) => unwrap_base64_lines(input)?,
+        (DecodeMode::Strict, _) => input.to_string(),
+    };
+    engine
+        .decode(normalized.as_bytes())
+        .map_err(|error| invalid_input(&format!("Invalid base64 input: {error}")))
+}
+
+#[inline(always)]
+fn base16_decode_bytes(input: &[u8]) -> napi::Result<Vec<u8>> {
+    hex::decode(input).map_err(|error| invalid_input(&format!("Invalid hex input: {error}")))
+}
+
+#[inline(always)]
+fn normalize_base32_variant(variant: Option<String>) -> napi::Result<Base32Variant> {
+    let Some(value) = variant else {
+        return Ok(Base32Variant::Rfc4648);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "rfc4648" | "standard" => Ok(Base32Variant::Rfc4648),
+        "hex" | "base32hex" => Ok(Base32Variant::Hex),
+        "crockford" => Ok(Base32Variant::Crockford),
+        "zbase32" | "z-base-32" => Ok(Base32Variant::ZBase32),
+        _ => Err(invalid_input(
+            "Base32 variant must be one of: rfc4648, hex, crockford, zbase32",
+        )),
+    }
+}
+
+#[inline(always)]
+fn base32_alphabet(variant: &Base32Variant) -> &'static [u8; 32] {
+    match variant {
+        Base32Variant::Rfc4648 => BASE32_ALPHABET,
+        Base32Variant::Hex => BASE32_HEX_ALPHABET,
+        Base32Variant::Crockford => BASE32_CROCKFORD_ALPHABET,
+        Base32Variant::ZBase32 => BASE32_ZBASE32_ALPHABET,
+    }
+}
+
+#[inline(always)]
+fn base32_default_padding(variant: &Base32Variant) -> bool {
+    matches!(variant, Base32Variant::Rfc4648 | Base32Variant::Hex)
+}
+
+#[inline(always)]
+fn base32_canonical_char(ch: u8, variant: &Base32Variant) -> u8 {
+    match variant {
+        Base32Variant::Rfc4648 | Base32Variant::Hex => ch.to_ascii_uppercase(),
+        Base32Variant::ZBase32 => ch.to_ascii_lowercase(),
+        Base32Variant::Crockford => match ch.to_ascii_uppercase() {
+            b'I' | b'L' => b'1',
+            b'O' => b'0',
+            upper => upper,
+        },
+    }
+}
+
+#[inline(always)]
+fn base32_value(ch: u8, variant: &Base32Variant, mode: &DecodeMode) -> Option<u8> {
+    let canonical = base32_canonical_char(ch, variant);
+    let case_sensitive = !matches!(variant, Base32Variant::Crockford);
+    if matches!(mode, DecodeMode::Strict) && case_sensitive && canonical != ch {
+        return None;
+    }
+    base32_alphabet(variant)
+        .iter()
+        .position(|value| *value == canonical)
+        .map(|index| index as u8)
+}
+
+#[inline(always)]
+fn base32_encode_bytes(input: &[u8], variant: &Base32Variant, padding: bool) -> String {
+    if input.is_empty() {
+        return String::new();
+    }
+
+    let alphabet = base32_alphabet(variant);
+    let mut output = String::new();
+    let mut buffer: u16 = 0;
+    let mut bits: u8 = 0;
+    for byte in input {
+        buffer = (buffer << 8) | (*byte as u16);
+        bits += 8;
+        while bits >= 5 {
+            let index = ((buffer >> (bits - 5)) & 0x1f) as usize;
+            output.push(alphabet[index] as char);
+            bits -= 5;
+        }
+    }
+
+    if bits > 0 {
+        let index = ((buffer << (5 - bits)) & 0x1f) as usize;
+        output.push(alphabet[index] as char);
+    }
+    while padding && !output.len().is_multiple_of(BASE32_GROUP_CHARS) {
+        output.push('=');
+    }
+    output
+}
+
+#[inline(always)]
+fn base32_check_padding(data_chars: usize, pad_chars: usize, padding: bool) -> napi::Result<()> {
+    if !padding {
+        if pad_chars > 0 {
+            return Err(invalid_input(
+                "Invalid base32 input: padding is not allowed",
+            ));
+        }
+        return Ok(());
+    }
+
+    let remainder = data_chars % BASE32_GROUP_CHARS;
+    let expected = (BASE32_GROUP_CHARS - remainder) % BASE32_GROUP_CHARS;
+    if pad_chars != expected {
+        return Err(invalid_input("Invalid base32 input: incorrect padding"));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn base32_decode_bytes(
+    input: &str,
+    variant: &Base32Variant,
+    padding: bool,
+    mode: &DecodeMode,
+) -> napi::Result<Vec<u8>> {
+    let strict = matches!(mode, DecodeMode::Strict);
+    let mut output = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u8 = 0;
+    let mut data_chars = 0_usize;
+    let mut pad_chars = 0_usize;
+    for (index, raw) in input.bytes().enumerate() {
+        if raw == b'-' && matches!(variant, Base32Variant::Crockford) {
+            continue;
+        }
+        if raw.is_ascii_whitespace() && !strict {
+            continue;
+        }
+        if raw == b'=' {
+            if !strict {
+                break;
+            }
+            if input.bytes().skip(index).any(|ch| ch != b'=') {
+                return Err(invalid_input(
+                    "Invalid base32 input: unexpected data after padding",
+                ));
+            }
+            pad_chars = input.len() - index;
+            break;
+        }
+
+        let Some(value) = base32_value(raw, variant, mode) else {
+            return Err(invalid_input("Invalid base32 input"));
+        };
+        data_chars += 1;
+        buffer = (buffer << 5) | (value as u32);
+        bits += 5;
+        while bits >= 8 {
+            let byte = ((buffer >> (bits - 8)) & 0xff) as u8;
+            output.push(byte);
+            bits -= 8;
+        }
+    }
+    if !strict {
+        return Ok(output);
+    }
+
+    if matches!(data_chars % BASE32_GROUP_CHARS, 1 | 3 | 6) {
+        return Err(invalid_input("Invalid base32 input: incorrect length"));
+    }
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(invalid_input(
+            "Invalid base32 input: non-zero trailing bits",
+        ));
+    }
+    base32_check_padding(data_chars, pad_chars, padding)?;
+    Ok(output)
+}
+
+#[inline(always)]
+fn base32_decode_with(input: &str, options: Base32Options) -> napi::Result<Vec<u8>> {
+    let variant = normalize_base32_variant(options.variant)?;
+    let mode = match options.mode {
+        Some(value) => normalize_decode_mode(Some(value))?,
+        None => DecodeMode::Lenient,
+    };
+    let padding = options
+        .padding
+        .unwrap_or_else(|| base32_default_padding(&variant));
+    base32_decode_bytes(input, &variant, padding, &mode)
+}
+
+#[inline(always)]
+fn normalize_aes_iv(iv_hex: &str) -> napi::Result<[u8; AES_IV_BYTES]> {
+    if iv_hex.len() != AES_IV_HEX_CHARS {
+        return Err(invalid_input(
+            "AES IV must be 16 bytes encoded as 32 hex characters",
+        ));
+    }
+    let decoded = hex::decode(iv_hex).map_err(|_| invalid_input("AES IV must be valid hex"))?;
+    if decoded.len() != AES_IV_BYTES {
+        return Err(invalid_input(
+            "AES IV must be 16 bytes encoded as 32 hex characters",
+        ));
+    }
+
+    let mut iv = [0_u8; AES_IV_BYTES];
+    iv.copy_from_slice(&decoded);
+    Ok(iv)
+}
+
+#[inline(always)]
+fn generate_aes_iv_hex() -> String {
+    let mut iv = [0_u8; AES_IV_BYTES];
+    let mut rng = OsRng;
+    rng.fill_bytes(&mut iv);
+    hex::encode(iv)
+}
+
+#[inline(always)]
+fn derive_aes_key(key: &str) -> [u8; AES_KEY_BYTES] {
+    digest_sha256(key.as_bytes())
+}
+
+#[inline(always)]
+fn aes_encrypt(
+    input: &[u8],
+    key: &[u8; AES_KEY_BYTES],
+    iv: &[u8; AES_IV_BYTES],
+) -> napi::Result<Vec<u8>> {
+    let cipher = Aes256CbcEnc::new_from_slices(key, iv)
+        .map_err(|_| invalid_input("AES key or IV is invalid"))?;
+    let mut buffer = vec![0_u8; input.len() + AES_BLOCK_BYTES];
+    buffer[..input.len()].copy_from_slice(input);
+    let encrypted = cipher
+        .encrypt_padded_mut::<Pkcs7>(&mut buffer, input.len())
+        .map_err(|_| invalid_input("AES encryption failed"))?;
+    Ok(encrypted.to_vec())
+}
+
+#[inline(always)]
+fn aes_decrypt(
+    input: &[u8],
+    key: &[u8; AES_KEY_BYTES],
+    iv: &[u8; AES_IV_BYTES],
+) -> napi::Result<Vec<u8>> {
+    let cipher = Aes256CbcDec::new_from_slices(key, iv)
+        .map_err(|_| invalid_input("AES key or IV is invalid"))?;
+    let mut buffer = input.to_vec();
+    let decrypted = cipher
+        .decrypt_padded_mut::<Pkcs7>(&mut buffer)
+        .map_err(|_| invalid_input("AES decryption failed"))?;
+    Ok(decrypted.to_vec())
+}
+
+#[inline(always)]
+fn normalize_rsa_bits(bits: Option<u32>) -> napi::Result<u32> {
+    let value = bits.unwrap_or(DEFAULT_RSA_BITS);
+    if !(MIN_RSA_BITS..=MAX_RSA_BITS).contains(&value) {
+        return Err(invalid_input("RSA bits must be in range 2048..=8192"));
+    }
+    if !value.is_multiple_of(RSA_BITS_STEP) {
+        return Err(invalid_input("RSA bits must be a multiple of 256"));
+    }
+    Ok(value)
+}
+
+#[inline(always)]
+fn normalize_rsa_encoding(encoding: Option<String>) -> napi::Result<BinaryEncoding> {
+    normalize_binary_encoding(encoding)
+}
+
+#[inline(always)]
+fn rsa_generate_keys(bits: u32) -> napi::Result<(RsaPrivateKey, RsaPublicKey)> {
+    let mut rng = OsRng;
+    let private = RsaPrivateKey::new(&mut rng, bits as usize)
+        .map_err(|error| invalid_input(&format!("RSA key generation failed: {error}")))?;
+    let public = RsaPublicKey::from(&private);
+    Ok((private, public))
+}
+
+#[inline(always)]
+fn rsa_private_to_pem(private: &RsaPrivateKey) -> napi::Result<String> {
+    private
+        .to_pkcs8_pem(LineEnding::LF)
+        .map(|pem| pem.to_string())
+        .map_err(|error| invalid_input(&format!("RSA private key export failed: {error}")))
+}
+
+#[inline(always)]
+fn rsa_public_to_pem(public: &RsaPublicKey) -> napi::Result<String> {
+    public
+        .to_public_key_pem(LineEnding::LF)
+        .map(|pem| pem.to_string())
+        .map_err(|error| invalid_input(&format!("RSA public key export failed: {error}")))
+}
+
+#[inline(always)]
+fn rsa_private_from_pem(pem: &str) -> napi::Result<RsaPrivateKey> {
+    RsaPrivateKey::from_pkcs8_pem(pem)
+        .map_err(|error| invalid_input(&format!("RSA private key is invalid: {error}")))
+}
+
+#[inline(always)]
+fn rsa_encrypt(public: &RsaPublicKey, input: &[u8]) -> napi::Result<Vec<u8>> {
+    let mut rng = OsRng;
+    public
+        .encrypt(&mut rng, Oaep::new::<Sha256>(), input)
+        .map_err(|error| invalid_input(&format!("RSA encryption failed: {error}")))
+}
+
+#[inline(always)]
+fn rsa_decrypt(private: &RsaPrivateKey, input: &[u8]) -> napi::Result<Vec<u8>> {
+    private
+        .decrypt(Oaep::new::<Sha256>(), input)
+        .map_err(|error| invalid_input(&format!("RSA decryption failed: {error}")))
+}
+
+#[inline(always)]
+fn aes_encode_bytes(input: &[u8], key: &str) -> napi::Result<(Vec<u8>, String)> {
+    let key_bytes = derive_aes_key(key);
+    let iv_hex = generate_aes_iv_hex();
+    let iv_bytes = normalize_aes_iv(&iv_hex)?;
+    let encrypted = aes_encrypt(input, &key_bytes, &iv_bytes)?;
+    Ok((encrypted, iv_hex))
+}
+
+#[inline(always)]
+fn aes_decode_bytes(encrypted: &[u8], iv: &[u8; AES_IV_BYTES], key: &str) -> napi::Result<String> {
+    let key_bytes = derive_aes_key(key);
+    let decrypted = aes_decrypt(encrypted, &key_bytes, iv)?;
+    String::from_utf8(decrypted)
+        .map_err(|error| invalid_input(&format!("Decrypted bytes are not valid UTF-8: {error}")))
+}
+
+#[inline(always)]
+fn rsa_encode_bytes(input: &[u8], bits: u32) -> napi::Result<(Vec<u8>, String, String)> {
+    let (private, public) = rsa_generate_keys(bits)?;
+    let encrypted = rsa_encrypt(&public, input)?;
+    let private_key = rsa_private_to_pem(&private)?;
+    let public_key = rsa_public_to_pem(&public)?;
+    Ok((encrypted, private_key, public_key))
+}
+
+#[inline(always)]
+fn rsa_decode_bytes(encrypted: &[u8], private_key: &str) -> napi::Result<String> {
+    let private = rsa_private_from_pem(private_key)?;
+    let decrypted = rsa_decrypt(&private, encrypted)?;
+    String::from_utf8(decrypted)
+        .map_err(|error| invalid_input(&format!("RSA plaintext is not valid UTF-8: {error}")))
+}
+
+#[inline(always)]
+fn rsa_packet_parts(packet: &RsaPacket) -> napi::Result<Vec<u8>> {
+    if packet.private_key.trim().is_empty() {
+        return Err(invalid_input("RSA packet.privateKey must not be empty"));
+    }
+
+    let encoding = if packet.encoding.trim().is_empty() {
+        None
+    } else {
+        Some(packet.encoding.clone())
+    };
+    decode_bytes_with_optional_encoding(&packet.encoded, encoding)
+}
+
+#[inline(always)]
+fn digest_task(
+    algorithm: HashAlgorithm,
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    let encoding = normalize_hash_encoding(encoding)?;
+    Ok(AsyncTask::with_optional_signal(
+        DigestTask {
+            algorithm,
+            input: input_bytes(&input).to_vec(),
+            encoding,
+        },
+        signal,
+    ))
+}
+
+impl Task for DigestTask {
+    type Output = Vec<u8>;
+    type JsValue = BinaryOutput;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        Ok(digest_with(&self.algorithm, &self.input))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(encode_bytes(&output, &self.encoding))
+    }
+}
+
+impl Task for Blake3Task {
+    type Output = Vec<u8>;
+    type JsValue = BinaryOutput;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        digest_blake3(
+            &self.input,
+            self.key.as_deref(),
+            self.context.as_deref(),
+            self.length,
+        )
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(encode_bytes(&output, &self.encoding))
+    }
+}
+
+impl Task for Pbkdf2Task {
+    type Output = Vec<u8>;
+    type JsValue = Buffer;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        Ok(pbkdf2_with(
+            &self.algorithm,
+            &self.password,
+            &self.salt,
+            self.iterations,
+            self.length,
+        ))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output.into())
+    }
+}
+
+impl Task for AesEncodeTask {
+    type Output = (Vec<u8>, String);
+    type JsValue = Vec<BinaryOutput>;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        aes_encode_bytes(&self.input, &self.key)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        let (encrypted, iv_hex) = output;
+        Ok(vec![
+            encode_bytes(&encrypted, &self.encoding),
+            Either::A(iv_hex),
+        ])
+    }
+}
+
+impl Task for AesDecodeTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        aes_decode_bytes(&self.encrypted, &self.iv, &self.key)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+impl Task for RsaEncodeTask {
+    type Output = (Vec<u8>, String, String);
+    type JsValue = RsaPacket;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        rsa_encode_bytes(&self.input, self.bits)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        let (encrypted, private_key, public_key) = output;
+        Ok(RsaPacket {
+            encoded: encode_bytes(&encrypted, &self.encoding),
+            private_key,
+            public_key,
+            encoding: binary_encoding_name(&self.encoding).to_string(),
+            bits: self.bits,
+        })
+    }
+}
+
+impl Task for RsaDecodeTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        rsa_decode_bytes(&self.encrypted, &self.private_key)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+#[napi(namespace = "base64", js_name = "encode")]
+pub fn base64_encode(input: ByteInput, options: Option<Base64Options>) -> napi::Result<String> {
+    base64_encode_with(input_bytes(&input), options.unwrap_or_default())
+}
+
+#[napi(namespace = "base64", js_name = "decode")]
+pub fn base64_decode(input: ByteInput, options: Option<Base64Options>) -> napi::Result<String> {
+    let decoded = base64_decode_with(input_text(&input)?, options.unwrap_or_default())?;
+    decoded_text(decoded)
+}
+
+#[napi(namespace = "base64", js_name = "decodeBytes")]
+pub fn base64_decode_buffer(
+    input: ByteInput,
+    options: Option<Base64Options>,
+) -> napi::Result<Buffer> {
+    let decoded = base64_decode_with(input_text(&input)?, options.unwrap_or_default())?;
+    Ok(decoded.into())
+}
+
+#[napi(namespace = "base16", js_name = "encode")]
+pub fn base16_encode(input: ByteInput) -> String {
+    hex::encode(input_bytes(&input))
+}
+
+#[napi(namespace = "base16", js_name = "decode")]
+pub fn base16_decode(input: ByteInput) -> napi::Result<String> {
+    decoded_text(base16_decode_bytes(input_bytes(&input))?)
+}
+
+#[napi(namespace = "base16", js_name = "decodeBytes")]
+pub fn base16_decode_buffer(input: ByteInput) -> napi::Result<Buffer> {
+    Ok(base16_decode_bytes(input_bytes(&input))?.into())
 }
 
 #[napi(namespace = "base32", js_name = "encode")]
-pub fn base32_encode(input: String) -> String {
-    base32_encode_bytes(input.as_bytes())
+pub fn base32_encode(input: ByteInput, options: Option<Base32Options>) -> napi::Result<String> {
+    let options = options.unwrap_or_default();
+    let variant = normalize_base32_variant(options.variant)?;
+    let padding = options
+        .padding
+        .unwrap_or_else(|| base32_default_padding(&variant));
+    Ok(base32_encode_bytes(input_bytes(&input), &variant, padding))
 }
 
 #[napi(namespace = "base32", js_name = "decode")]
-pub fn base32_decode(input: String) -> napi::Result<String> {
-    let decoded = base32_decode_bytes(&input)?;
-    String::from_utf8(decoded)
-        .map_err(|error| invalid_input(&format!("Decoded bytes are not valid UTF-8: {error}")))
+pub fn base32_decode(input: ByteInput, options: Option<Base32Options>) -> napi::Result<String> {
+    let decoded = base32_decode_with(input_text(&input)?, options.unwrap_or_default())?;
+    decoded_text(decoded)
+}
+
+#[napi(namespace = "base32", js_name = "decodeBytes")]
+pub fn base32_decode_buffer(
+    input: ByteInput,
+    options: Option<Base32Options>,
+) -> napi::Result<Buffer> {
+    let decoded = base32_decode_with(input_text(&input)?, options.unwrap_or_default())?;
+    Ok(decoded.into())
+}
+
+#[napi(namespace = "crypto")]
+pub fn md5(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_md5(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto")]
+pub fn sha256(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha256(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto")]
+pub fn sha512(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha512(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto")]
+pub fn sha1(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha1(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto")]
+pub fn sha224(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha224(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto")]
+pub fn sha384(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha384(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto", js_name = "sha512_256")]
+pub fn sha512_256(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha512_256(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto", js_name = "sha3_256")]
+pub fn sha3_256(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha3_256(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto", js_name = "sha3_512")]
+pub fn sha3_512(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_sha3_512(input.as_bytes());
+    let mode = normalize_hash_encoding(encoding)?;
+    Ok(encode_bytes(&digest, &mode))
 }
 
 #[napi(namespace = "crypto")]
-pub fn md5(input: String, encoding: Option<String>) -> napi::Result<String> {
-    let digest = digest_md5(&input);
+pub fn keccak256(input: String, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+    let digest = digest_keccak256(input.as_bytes());
     let mode = normalize_hash_encoding(encoding)?;
     Ok(encode_bytes(&digest, &mode))
 }
 
+#[napi(namespace = "crypto", js_name = "md5Async")]
+pub fn md5_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Md5, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha1Async")]
+pub fn sha1_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha1, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha224Async")]
+pub fn sha224_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha224, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha256Async")]
+pub fn sha256_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha256, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha384Async")]
+pub fn sha384_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha384, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha512Async")]
+pub fn sha512_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha512, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha512_256Async")]
+pub fn sha512_256_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha512_256, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha3_256Async")]
+pub fn sha3_256_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha3_256, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "sha3_512Async")]
+pub fn sha3_512_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Sha3_512, input, encoding, signal)
+}
+
+#[napi(namespace = "crypto", js_name = "keccak256Async")]
+pub fn keccak256_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<DigestTask>> {
+    digest_task(HashAlgorithm::Keccak256, input, encoding, signal)
+}
+
 #[napi(namespace = "crypto")]
-pub fn sha256(input: String, encoding: Option<String>) -> napi::Result<String> {
-    let digest = digest_sha256(&input);
+pub fn shake128(
+    input: String,
+    length: Option<u32>,
+    encoding: Option<String>,
+) -> napi::Result<BinaryOutput> {
+    let length = normalize_xof_length(length, DEFAULT_SHAKE128_BYTES)?;
     let mode = normalize_hash_encoding(encoding)?;
+    let digest = digest_xof::<Shake128>(input.as_bytes(), length);
     Ok(encode_bytes(&digest, &mode))
 }
 
 #[napi(namespace = "crypto")]
-pub fn sha512(input: String, encoding: Option<String>) -> napi::Result<String> {
-    let digest = digest_sha512(&input);
+pub fn shake256(
+    input: String,
+    length: Option<u32>,
+    encoding: Option<String>,
+) -> napi::Result<BinaryOutput> {
+    let length = normalize_xof_length(length, DEFAULT_SHAKE256_BYTES)?;
     let mode = normalize_hash_encoding(encoding)?;
+    let digest = digest_xof::<Shake256>(input.as_bytes(), length);
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto", js_name = "blake2b")]
+pub fn blake2b(input: ByteInput, options: Option<Blake2Options>) -> napi::Result<BinaryOutput> {
+    let options = options.unwrap_or_default();
+    let length = normalize_blake_length(options.length, BLAKE2B_MAX_BYTES, BLAKE2B_MAX_BYTES)?;
+    let mode = normalize_hash_encoding(options.encoding)?;
+    let key = options.key.as_ref().map(input_bytes).unwrap_or_default();
+    let digest = digest_blake2b(input_bytes(&input), key, length)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto", js_name = "blake2s")]
+pub fn blake2s(input: ByteInput, options: Option<Blake2Options>) -> napi::Result<BinaryOutput> {
+    let options = options.unwrap_or_default();
+    let length = normalize_blake_length(options.length, BLAKE2S_MAX_BYTES, BLAKE2S_MAX_BYTES)?;
+    let mode = normalize_hash_encoding(options.encoding)?;
+    let key = options.key.as_ref().map(input_bytes).unwrap_or_default();
+    let digest = digest_blake2s(input_bytes(&input), key, length)?;
+    Ok(encode_bytes(&digest, &mode))
+}
+
+#[napi(namespace = "crypto")]
+pub fn blake3(input: ByteInput, options: Option<Blake3Options>) -> napi::Result<BinaryOutput> {
+    let options = options.unwrap_or_default();
+    let length = normalize_xof_length(options.length, DEFAULT_BLAKE3_BYTES)?;
+    let mode = normalize_hash_encoding(options.encoding)?;
+    let key = options.key.as_ref().map(input_bytes);
+    let digest = digest_blake3(input_bytes(&input), key, options.context.as_deref(), length)?;
     Ok(encode_bytes(&digest, &mode))
 }
 
+#[napi(namespace = "crypto", js_name = "blake3Async")]
+pub fn blake3_async(
+    input: ByteInput,
+    options: Option<Blake3Options>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<Blake3Task>> {
+    let options = options.unwrap_or_default();
+    let length = normalize_xof_length(options.length, DEFAULT_BLAKE3_BYTES)?;
+    let encoding = normalize_hash_encoding(options.encoding)?;
+    let key = options.key.as_ref().map(|key| input_bytes(key).to_vec());
+    blake3_hasher(key.as_deref(), options.context.as_deref())?;
+    Ok(AsyncTask::with_optional_signal(
+        Blake3Task {
+            input: input_bytes(&input).to_vec(),
+            key,
+            context: options.context,
+            length,
+            encoding,
+        },
+        signal,
+    ))
+}
+
+#[napi(namespace = "crypto")]
+pub fn hmac(
+    algorithm: String,
+    key: ByteInput,
+    message: ByteInput,
+    encoding: Option<String>,
+) -> napi::Result<BinaryOutput> {
+    let algorithm = normalize_hash_algorithm(&algorithm)?;
+    let mode = normalize_hash_encoding(encoding)?;
+    let mut state = hmac_state(&algorithm, input_bytes(&key));
+    hmac_update(&mut state, input_bytes(&message));
+    Ok(encode_bytes(&hmac_finalize(state), &mode))
+}
+
+#[napi(namespace = "crypto", js_name = "hmacVerify")]
+pub fn hmac_verify_signature(
+    algorithm: String,
+    key: ByteInput,
+    message: ByteInput,
+    signature: ByteInput,
+    encoding: Option<String>,
+) -> napi::Result<bool> {
+    let algorithm = normalize_hash_algorithm(&algorithm)?;
+    if let Some(value) = &encoding {
+        normalize_encoding_value(value)?;
+    }
+    let Ok(expected) = decode_bytes_with_optional_encoding(&signature, encoding) else {
+        return Ok(false);
+    };
+    let mut state = hmac_state(&algorithm, input_bytes(&key));
+    hmac_update(&mut state, input_bytes(&message));
+    Ok(hmac_verify(state, &expected))
+}
+
+#[napi(namespace = "crypto", js_name = "timingSafeEqual")]
+pub fn timing_safe_equal(left: ByteInput, right: ByteInput) -> bool {
+    constant_time_eq(input_bytes(&left), input_bytes(&right))
+}
+
+#[napi(namespace = "crypto", js_name = "verifyDigest")]
+pub fn verify_digest(
+    input: ByteInput,
+    expected: ByteInput,
+    algorithm: Option<String>,
+    encoding: Option<String>,
+) -> napi::Result<bool> {
+    let algorithm = normalize_kdf_hash(algorithm)?;
+    if let Some(value) = &encoding {
+        normalize_encoding_value(value)?;
+    }
+    let digest = digest_with(&algorithm, input_bytes(&input));
+    let Ok(expected) = decode_bytes_with_optional_encoding(&expected, encoding) else {
+        return Ok(false);
+    };
+    Ok(constant_time_eq(&digest, &expected))
+}
+
+#[napi(namespace = "crypto")]
+pub fn hkdf(
+    ikm: ByteInput,
+    salt: ByteInput,
+    info: ByteInput,
+    length: u32,
+    hash: Option<String>,
+) -> napi::Result<Buffer> {
+    let algorithm = normalize_kdf_hash(hash)?;
+    let length = normalize_derived_length(length)?;
+    let output = hkdf_with(
+        &algorithm,
+        input_bytes(&ikm),
+        input_bytes(&salt),
+        input_bytes(&info),
+        length,
+    )?;
+    Ok(output.into())
+}
+
+#[napi(namespace = "crypto")]
+pub fn pbkdf2(
+    password: ByteInput,
+    salt: ByteInput,
+    iterations: u32,
+    length: u32,
+    hash: Option<String>,
+) -> napi::Result<Buffer> {
+    let algorithm = normalize_kdf_hash(hash)?;
+    let length = normalize_derived_length(length)?;
+    if iterations == 0 {
+        return Err(invalid_input("PBKDF2 iterations must be greater than 0"));
+    }
+    let output = pbkdf2_with(
+        &algorithm,
+        input_bytes(&password),
+        input_bytes(&salt),
+        iterations,
+        length,
+    );
+    Ok(output.into())
+}
+
+#[napi(namespace = "crypto", js_name = "pbkdf2Async")]
+pub fn pbkdf2_async(
+    password: ByteInput,
+    salt: ByteInput,
+    iterations: u32,
+    length: u32,
+    hash: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<Pbkdf2Task>> {
+    let algorithm = normalize_kdf_hash(hash)?;
+    let length = normalize_derived_length(length)?;
+    if iterations == 0 {
+        return Err(invalid_input("PBKDF2 iterations must be greater than 0"));
+    }
+    Ok(AsyncTask::with_optional_signal(
+        Pbkdf2Task {
+            algorithm,
+            password: input_bytes(&password).to_vec(),
+            salt: input_bytes(&salt).to_vec(),
+            iterations,
+            length,
+        },
+        signal,
+    ))
+}
+
+#[napi(namespace = "crypto", js_name = "Hmac")]
+pub struct HmacStream {
+    state: Option<HmacState>,
+}
+
+#[napi(namespace = "crypto")]
+impl HmacStream {
+    #[napi(constructor)]
+    pub fn new(algorithm: String, key: ByteInput) -> napi::Result<Self> {
+        let algorithm = normalize_hash_algorithm(&algorithm)?;
+        Ok(Self {
+            state: Some(hmac_state(&algorithm, input_bytes(&key))),
+        })
+    }
+
+    #[napi]
+    pub fn update(&mut self, input: ByteInput) -> napi::Result<()> {
+        let Some(state) = self.state.as_mut() else {
+            return Err(invalid_input("HMAC digest has already been computed"));
+        };
+        hmac_update(state, input_bytes(&input));
+        Ok(())
+    }
+
+    #[napi]
+    pub fn digest(&mut self, encoding: Option<String>) -> napi::Result<BinaryOutput> {
+        let mode = normalize_hash_encoding(encoding)?;
+        let Some(state) = self.state.take() else {
+            return Err(invalid_input("HMAC digest has already been computed"));
+        };
+        Ok(encode_bytes(&hmac_finalize(state), &mode))
+    }
+}
+
+#[napi(namespace = "merkle")]
+pub fn root(
+    leaves: Vec<ByteInput>,
+    hash: Option<String>,
+    encoding: Option<String>,
+) -> napi::Result<BinaryOutput> {
+    let algorithm = normalize_kdf_hash(hash)?;
+    let mode = normalize_hash_encoding(encoding)?;
+    let root = merkle_root_bytes(&algorithm, &leaves);
+    Ok(encode_bytes(&root, &mode))
+}
+
+#[napi(namespace = "merkle")]
+pub fn proof(
+    leaves: Vec<ByteInput>,
+    index: u32,
+    hash: Option<String>,
+    encoding: Option<String>,
+) -> napi::Result<MerkleProof> {
+    let algorithm = normalize_kdf_hash(hash)?;
+    let mode = normalize_hash_encoding(encoding)?;
+    if index as usize >= leaves.len() {
+        return Err(invalid_input("Merkle leaf index is out of range"));
+    }
+    let size = u32::try_from(leaves.len())
+        .map_err(|_| invalid_input("Merkle tree has too many leaves"))?;
+
+    let hashes = merkle_leaf_hashes(&algorithm, &leaves);
+    let mut path = Vec::new();
+    merkle_audit_path(&algorithm, &hashes, index as usize, &mut path);
+    Ok(MerkleProof {
+        index,
+        size,
+        hash: hash_algorithm_name(&algorithm).to_string(),
+        encoding: binary_encoding_name(&mode).to_string(),
+        siblings: path.iter().map(|node| encode_bytes(node, &mode)).collect(),
+    })
+}
+
+#[napi(namespace = "merkle")]
+pub fn verify(root: ByteInput, leaf: ByteInput, proof: MerkleProof) -> napi::Result<bool> {
+    let algorithm = normalize_hash_algorithm(&proof.hash)?;
+    let mode = normalize_encoding_value(&proof.encoding)?;
+    let expected = decode_input(&root, &mode)?;
+    let mut path = Vec::with_capacity(proof.siblings.len());
+    for sibling in &proof.siblings {
+        path.push(decode_input(sibling, &mode)?);
+    }
+
+    let leaf_hash = merkle_leaf_hash(&algorithm, input_bytes(&leaf));
+    let computed = merkle_root_from_path(
+        &algorithm,
+        leaf_hash,
+        proof.index as u64,
+        proof.size as u64,
+        &path,
+    );
+    Ok(computed.is_some_and(|hash| constant_time_eq(&hash, &expected)))
+}
+
 #[napi(namespace = "AES", js_name = "encode")]
 pub fn aes_encode(
     input: String,
     key: String,
     encoding: Option<String>,
-) -> napi::Result<Vec<String>> {
+) -> napi::Result<Vec<BinaryOutput>> {
     let mode = normalize_binary_encoding(encoding)?;
-    let key_bytes = derive_aes_key(&key);
-    let iv_hex = generate_aes_iv_hex();
-    let iv_bytes = normalize_aes_iv(&iv_hex)?;
-    let encrypted = aes_encrypt(input.as_bytes(), &key_bytes, &iv_bytes)?;
+    let (encrypted, iv_hex) = aes_encode_bytes(input.as_bytes(), &key)?;
     let encoded = encode_bytes(&encrypted, &mode);
-    Ok(vec![encoded, iv_hex])
+    Ok(vec![encoded, Either::A(iv_hex)])
+}
+
+#[napi(namespace = "AES", js_name = "encodeAsync")]
+pub fn aes_encode_async(
+    input: ByteInput,
+    key: String,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<AesEncodeTask>> {
+    let encoding = normalize_binary_encoding(encoding)?;
+    Ok(AsyncTask::with_optional_signal(
+        AesEncodeTask {
+            input: input_bytes(&input).to_vec(),
+            key,
+            encoding,
+        },
+        signal,
+    ))
 }
 
 #[napi(namespace = "AES", js_name = "decode")]
 pub fn aes_decode(
-    encoded: String,
+    encoded: ByteInput,
     iv_hex: String,
     key: String,
     encoding: Option<String>,
 ) -> napi::Result<String> {
     let iv = normalize_aes_iv(&iv_hex)?;
-    let key_bytes = derive_aes_key(&key);
     let encrypted = decode_bytes_with_optional_encoding(&encoded, encoding)?;
-    let decrypted = aes_decrypt(&encrypted, &key_bytes, &iv)?;
-    String::from_utf8(decrypted)
-        .map_err(|error| invalid_input(&format!("Decrypted bytes are not valid UTF-8: {error}")))
+    aes_decode_bytes(&encrypted, &iv, &key)
+}
+
+#[napi(namespace = "AES", js_name = "decodeAsync")]
+pub fn aes_decode_async(
+    encoded: ByteInput,
+    iv_hex: String,
+    key: String,
+    encoding: Option<String>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<AesDecodeTask>> {
+    let iv = normalize_aes_iv(&iv_hex)?;
+    let encrypted = decode_bytes_with_optional_encoding(&encoded, encoding)?;
+    Ok(AsyncTask::with_optional_signal(
+        AesDecodeTask { encrypted, iv, key },
+        signal,
+    ))
 }
 
 #[napi(namespace = "RSA", js_name = "encode")]
@@ -554,10 +2146,7 @@ pub fn rsa_encode(
 ) -> napi::Result<RsaPacket> {
     let mode = normalize_rsa_encoding(encoding)?;
     let bits = normalize_rsa_bits(bits)?;
-    let (private, public) = rsa_generate_keys(bits)?;
-    let encrypted = rsa_encrypt(&public, input.as_bytes())?;
-    let private_key = rsa_private_to_pem(&private)?;
-    let public_key = rsa_public_to_pem(&public)?;
+    let (encrypted, private_key, public_key) = rsa_encode_bytes(input.as_bytes(), bits)?;
 
     Ok(RsaPacket {
         encoded: encode_bytes(&encrypted, &mode),
@@ -570,71 +2159,91 @@ pub fn rsa_encode(
 
 #[napi(namespace = "RSA", js_name = "decode")]
 pub fn rsa_decode(packet: RsaPacket) -> napi::Result<String> {
-    if packet.private_key.trim().is_empty() {
-        return Err(invalid_input("RSA packet.privateKey must not be empty"));
-    }
+    let encrypted = rsa_packet_parts(&packet)?;
+    rsa_decode_bytes(&encrypted, &packet.private_key)
+}
 
-    let encoding = if packet.encoding.trim().is_empty() {
-        None
-    } else {
-        Some(packet.encoding.clone())
-    };
-    let encrypted = decode_bytes_with_optional_encoding(&packet.encoded, encoding)?;
-    let private = rsa_private_from_pem(&packet.private_key)?;
-    let decrypted = rsa_decrypt(&private, &encrypted)?;
-    String::from_utf8(decrypted)
-        .map_err(|error| invalid_input(&format!("RSA plaintext is not valid UTF-8: {error}")))
+#[napi(namespace = "RSA", js_name = "encodeAsync")]
+pub fn rsa_encode_async(
+    input: ByteInput,
+    encoding: Option<String>,
+    bits: Option<u32>,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<RsaEncodeTask>> {
+    let encoding = normalize_rsa_encoding(encoding)?;
+    let bits = normalize_rsa_bits(bits)?;
+    Ok(AsyncTask::with_optional_signal(
+        RsaEncodeTask {
+            input: input_bytes(&input).to_vec(),
+            encoding,
+            bits,
+        },
+        signal,
+    ))
+}
+
+#[napi(namespace = "RSA", js_name = "decodeAsync")]
+pub fn rsa_decode_async(
+    packet: RsaPacket,
+    signal: Option<AbortSignal>,
+) -> napi::Result<AsyncTask<RsaDecodeTask>> {
+    let encrypted = rsa_packet_parts(&packet)?;
+    Ok(AsyncTask::with_optional_signal(
+        RsaDecodeTask {
+            encrypted,
+            private_key: packet.private_key,
+        },
+        signal,
+    ))
 }
 
-#[napi(namespace = "morse", js_name = "encode")]
-pub fn morse_encode(input: String) -> String {
+#[napi(namespace = "crypto")]
+pub fn rot(input: String, shift: Option<i64>, options: Option<RotOptions>) -> napi::Result<String> {
+    let alphabet = normalize_rot_alphabet(options)?;
     if input.is_empty() {
-        return input;
+        return Ok(input);
     }
 
-    let mut output = Vec::new();
-    for ch in input.chars() {
-        if ch.is_whitespace() {
-            output.push("/".to_string());
-            continue;
+    let output = match alphabet {
+        RotAlphabet::Letters => {
+            let shift = normalize_rot_shift(shift, ALPHABET_SIZE as usize) as u8;
+            input
+                .chars()
+                .map(|ch| rotate_ascii_char(ch, shift))
+                .collect()
         }
-        if let Some(code) = morse_code_for(ch) {
-            output.push(code.to_string());
+        RotAlphabet::Digits => {
+            let shift = normalize_rot_shift(shift, DIGIT_COUNT as usize) as u8;
+            input
+                .chars()
+                .map(|ch| rotate_digit_char(ch, shift))
+                .collect()
         }
-    }
-    output.join(" ")
-}
-
-#[napi(namespace = "morse", js_name = "decode")]
-pub fn morse_decode(input: String) -> String {
-    if input.trim().is_empty() {
-        return String::new();
-    }
-
-    let mut output = String::new();
-    for token in input.split_whitespace() {
-        if token == "/" && should_insert_space(&output) {
-            output.push(' ');
-            continue;
+        RotAlphabet::LettersAndDigits => {
+            let letter_shift = normalize_rot_shift(shift, ALPHABET_SIZE as usize) as u8;
+            let digit_shift = normalize_rot_shift(shift, DIGIT_COUNT as usize) as u8;
+            input
+                .chars()
+                .map(|ch| rotate_digit_char(rotate_ascii_char(ch, letter_shift), digit_shift))
+                .collect()
         }
-        if token == "/" {
-            continue;
+        RotAlphabet::Ascii => {
+            let shift = normalize_rot_shift(shift, ROT47_SIZE as usize) as u8;
+            input
+                .chars()
+                .map(|ch| rotate_rot47_char(ch, shift))
+                .collect()
         }
-        if let Some(ch) = morse_char_for(token) {
-            output.push(ch);
+        RotAlphabet::Custom(chars) => {
+            let shift = normalize_rot_shift(shift, chars.len());
+            input
+                .chars()
+                .map(|ch| match chars.iter().position(|value| *value == ch) {
+                    Some(index) => chars[(index + shift) % chars.len()],
+                    None => ch,
+                })
+                .collect()
         }
-    }
-    output
-}
-
-#[napi(namespace = "crypto")]
-pub fn rot(input: String, shift: Option<u8>) -> String {
-    if input.is_empty() {
-        return input;
-    }
-    let normalized_shift = normalize_rot_shift(shift);
-    input
-        .chars()
-        .map(|ch| rotate_ascii_char(ch, normalized_shift))
-        .collect()
+    };
+    Ok(output)
 }
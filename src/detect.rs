@@ -0,0 +1,329 @@
+#![allow(dead_code)]
+use napi_derive::napi;
+
+use crate::codec::percent_decode_bytes;
+use crate::crack::english_fitness;
+use crate::crypto::{BinaryEncoding, decode_bytes, rotate_ascii_char};
+use crate::morse::morse_decode;
+
+const ALPHABET_SIZE: u8 = 26;
+const DEFAULT_UNWRAP_DEPTH: u32 = 16;
+const MAX_UNWRAP_DEPTH: u32 = 64;
+const UNWRAP_MIN_SCORE: f64 = 0.6;
+const MIN_ENGLISH_LETTERS: usize = 6;
+const ROT_MIN_WORDS: usize = 2;
+const ROT_MIN_FITNESS: f64 = -3.0;
+const ROT_MIN_MARGIN: f64 = 0.2;
+const ROT_MAX_SCORE: f64 = 0.9;
+const MORSE_CONFIDENCE: f64 = 0.95;
+const PERCENT_CONFIDENCE: f64 = 0.9;
+const BASE32_CONFIDENCE: f64 = 0.85;
+const HEX_CONFIDENCE: f64 = 0.8;
+const BASE64_CONFIDENCE: f64 = 0.75;
+const BASE64_MARKED_CONFIDENCE: f64 = 0.85;
+
+pub(crate) const ENGLISH_FREQUENCIES: [f64; 26] = [
+    0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094, 0.06966, 0.00153,
+    0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929, 0.00095, 0.05987, 0.06327, 0.09056,
+    0.02758, 0.00978, 0.02360, 0.00150, 0.01974, 0.00074,
+];
+
+struct Candidate {
+    encoding: String,
+    score: f64,
+    decoded: Option<String>,
+}
+
+#[napi(object)]
+pub struct DetectedEncoding {
+    pub encoding: String,
+    pub score: f64,
+    pub decoded: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct UnwrapOptions {
+    #[napi(js_name = "maxDepth")]
+    pub max_depth: Option<u32>,
+}
+
+#[napi(object)]
+pub struct UnwrapResult {
+    pub output: String,
+    pub layers: Vec<String>,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn text_quality(text: &str) -> f64 {
+    let mut total = 0_usize;
+    let mut printable = 0_usize;
+    for ch in text.chars() {
+        total += 1;
+        if !ch.is_control() || matches!(ch, '\n' | '\r' | '\t') {
+            printable += 1;
+        }
+    }
+    if total == 0 {
+        return 0.0;
+    }
+    printable as f64 / total as f64
+}
+
+#[inline(always)]
+fn bytes_quality(bytes: &[u8]) -> (f64, Option<String>) {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => (text_quality(text), Some(text.to_string())),
+        Err(_) => (0.0, None),
+    }
+}
+
+#[inline(always)]
+pub(crate) fn letter_counts(text: &str) -> ([usize; 26], usize) {
+    let mut counts = [0_usize; 26];
+    let mut total = 0;
+    for byte in text.bytes() {
+        if byte.is_ascii_alphabetic() {
+            counts[(byte.to_ascii_lowercase() - b'a') as usize] += 1;
+            total += 1;
+        }
+    }
+    (counts, total)
+}
+
+#[inline(always)]
+pub(crate) fn english_chi_squared(counts: &[usize; 26], total: usize) -> f64 {
+    counts
+        .iter()
+        .zip(ENGLISH_FREQUENCIES.iter())
+        .map(|(observed, frequency)| {
+            let expected = frequency * total as f64;
+            let delta = *observed as f64 - expected;
+            delta * delta / expected
+        })
+        .sum()
+}
+
+#[inline(always)]
+fn rotate_text(text: &str, shift: u8) -> String {
+    text.chars()
+        .map(|ch| rotate_ascii_char(ch, shift))
+        .collect()
+}
+
+#[inline(always)]
+fn is_hex_candidate(text: &str) -> bool {
+    text.len() >= 2
+        && text.len().is_multiple_of(2)
+        && text.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
+
+#[inline(always)]
+fn is_base64_candidate(text: &str) -> bool {
+    let body = text.trim_end_matches('=');
+    text.len() >= 4
+        && text.len() - body.len() <= 2
+        && !body.is_empty()
+        && body
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'/' | b'-' | b'_'))
+}
+
+#[inline(always)]
+fn is_base32_candidate(text: &str) -> bool {
+    let body = text.trim_end_matches('=');
+    text.len() >= 8
+        && !body.is_empty()
+        && body
+            .bytes()
+            .all(|byte| byte.is_ascii_uppercase() || (b'2'..=b'7').contains(&byte))
+}
+
+#[inline(always)]
+fn is_morse_candidate(text: &str) -> bool {
+    text.contains(['.', '-'])
+        && text
+            .chars()
+            .all(|ch| matches!(ch, '.' | '-' | '/' | ' ' | '\n' | '\t'))
+}
+
+#[inline(always)]
+fn binary_candidate(
+    encoding: &str,
+    confidence: f64,
+    text: &str,
+    binary: BinaryEncoding,
+) -> Option<Candidate> {
+    let bytes = decode_bytes(text, &binary).ok()?;
+    if bytes.is_empty() {
+        return None;
+    }
+    let (quality, decoded) = bytes_quality(&bytes);
+    Some(Candidate {
+        encoding: encoding.to_string(),
+        score: confidence * (0.5 + 0.5 * quality),
+        decoded,
+    })
+}
+
+#[inline(always)]
+fn base64_candidate(text: &str) -> Option<Candidate> {
+    let url = text.contains(['-', '_']);
+    if url && text.contains(['+', '/']) {
+        return None;
+    }
+    let marked = text.ends_with('=') || text.contains(['+', '/', '-', '_']);
+    let confidence = if marked {
+        BASE64_MARKED_CONFIDENCE
+    } else {
+        BASE64_CONFIDENCE
+    };
+    let (name, binary) = if url {
+        ("base64url", BinaryEncoding::Base64Url)
+    } else {
+        ("base64", BinaryEncoding::Base64NoPad)
+    };
+    binary_candidate(name, confidence, text, binary)
+}
+
+#[inline(always)]
+fn percent_candidate(text: &str) -> Option<Candidate> {
+    let escapes = text.matches('%').count();
+    if escapes == 0 {
+        return None;
+    }
+    let bytes = percent_decode_bytes(text).ok()?;
+    let (quality, decoded) = bytes_quality(&bytes);
+    Some(Candidate {
+        encoding: "percent".to_string(),
+        score: PERCENT_CONFIDENCE * (0.5 + 0.5 * quality),
+        decoded,
+    })
+}
+
+#[inline(always)]
+fn morse_candidate(text: &str) -> Option<Candidate> {
+    let decoded = morse_decode(text.to_string(), None).ok()?;
+    if decoded.is_empty() {
+        return None;
+    }
+    Some(Candidate {
+        encoding: "morse".to_string(),
+        score: MORSE_CONFIDENCE,
+        decoded: Some(decoded),
+    })
+}
+
+#[inline(always)]
+fn rot_candidate(text: &str) -> Option<Candidate> {
+    let (_, letters) = letter_counts(text);
+    if letters < MIN_ENGLISH_LETTERS || text.split_whitespace().count() < ROT_MIN_WORDS {
+        return None;
+    }
+    let mut shifts: Vec<(u8, f64)> = (0..ALPHABET_SIZE)
+        .map(|shift| (shift, english_fitness(&rotate_text(text, shift))))
+        .collect();
+    shifts.sort_by(|left, right| right.1.total_cmp(&left.1));
+    let (shift, fitness) = shifts[0];
+    let margin = fitness - shifts[1].1;
+    if shift == 0 || fitness < ROT_MIN_FITNESS || margin < ROT_MIN_MARGIN {
+        return None;
+    }
+    Some(Candidate {
+        encoding: format!("rot{}", ALPHABET_SIZE - shift),
+        score: (UNWRAP_MIN_SCORE + margin).min(ROT_MAX_SCORE),
+        decoded: Some(rotate_text(text, shift)),
+    })
+}
+
+#[inline(always)]
+fn detect_candidates(input: &str) -> Vec<Candidate> {
+    let text = input.trim();
+    let mut output = Vec::new();
+    if text.is_empty() {
+        return output;
+    }
+
+    if is_morse_candidate(text) {
+        output.extend(morse_candidate(text));
+    }
+    if is_hex_candidate(text) {
+        output.extend(binary_candidate(
+            "hex",
+            HEX_CONFIDENCE,
+            text,
+            BinaryEncoding::Hex,
+        ));
+    }
+    if is_base32_candidate(text) {
+        output.extend(binary_candidate(
+            "base32",
+            BASE32_CONFIDENCE,
+            text,
+            BinaryEncoding::Base32,
+        ));
+    }
+    if is_base64_candidate(text) {
+        output.extend(base64_candidate(text));
+    }
+    output.extend(percent_candidate(text));
+    output.extend(rot_candidate(text));
+
+    output.sort_by(|left, right| right.score.total_cmp(&left.score));
+    output
+}
+
+#[inline(always)]
+fn normalize_unwrap_depth(options: Option<UnwrapOptions>) -> napi::Result<u32> {
+    let depth = options
+        .unwrap_or_default()
+        .max_depth
+        .unwrap_or(DEFAULT_UNWRAP_DEPTH);
+    if depth == 0 || depth > MAX_UNWRAP_DEPTH {
+        return Err(invalid_input("maxDepth must be between 1 and 64"));
+    }
+    Ok(depth)
+}
+
+#[napi(namespace = "crypto")]
+pub fn detect(input: String) -> Vec<DetectedEncoding> {
+    detect_candidates(&input)
+        .into_iter()
+        .map(|candidate| DetectedEncoding {
+            encoding: candidate.encoding,
+            score: (candidate.score * 1000.0).round() / 1000.0,
+            decoded: candidate.decoded,
+        })
+        .collect()
+}
+
+#[napi(namespace = "crypto")]
+pub fn unwrap(input: String, options: Option<UnwrapOptions>) -> napi::Result<UnwrapResult> {
+    let depth = normalize_unwrap_depth(options)?;
+    let mut output = input;
+    let mut layers = Vec::new();
+    for _ in 0..depth {
+        let next = detect_candidates(&output).into_iter().find(|candidate| {
+            candidate.score >= UNWRAP_MIN_SCORE
+                && candidate.decoded.as_ref().is_some_and(|decoded| {
+                    decoded.trim() != output.trim() && text_quality(decoded) == 1.0
+                })
+        });
+        let Some(Candidate {
+            encoding,
+            decoded: Some(decoded),
+            ..
+        }) = next
+        else {
+            break;
+        };
+        layers.push(encoding);
+        output = decoded;
+    }
+    Ok(UnwrapResult { output, layers })
+}
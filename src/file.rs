@@ -89,7 +89,7 @@ where
 }
 
 #[inline(always)]
-fn sandbox_root() -> napi::Result<PathBuf> {
+pub(crate) fn sandbox_root() -> napi::Result<PathBuf> {
     let cached = SANDBOX_ROOT.get_or_init(|| {
         let current = std::env::current_dir()
             .map_err(|error| format!("Failed to get cwd: {error}"))?;
@@ -140,7 +140,7 @@ fn resolve_normal_component(
 }
 
 #[inline(always)]
-fn resolve_path(root: &Path, raw_path: &str) -> napi::Result<PathBuf> {
+pub(crate) fn resolve_path(root: &Path, raw_path: &str) -> napi::Result<PathBuf> {
     if raw_path.trim().is_empty() {
         return Err(invalid_input("Path must not be empty"));
     }
@@ -257,7 +257,11 @@ fn create_folder(root: &Path, raw_path: &str) -> napi::Result<bool> {
 }
 
 #[inline(always)]
-fn create_file(root: &Path, raw_path: &str, content: &str) -> napi::Result<bool> {
+pub(crate) fn create_file(
+    root: &Path,
+    raw_path: &str,
+    content: impl AsRef<[u8]>,
+) -> napi::Result<bool> {
     let target = resolve_path(root, raw_path)?;
     if target == root {
         return Err(invalid_input("Refusing to overwrite the repository root"));
@@ -1,5 +1,13 @@
+mod checksum;
+mod classic;
+mod codec;
+mod crack;
 mod crypto;
+mod detect;
 mod file;
+mod manifest;
+mod morse;
+mod password;
 mod rand;
 
 #[inline(always)]
@@ -19,13 +27,63 @@ fn move_namespace(
     Ok(())
 }
 
+#[inline(always)]
+fn group_namespaces(
+    env: &napi::Env,
+    exports: &mut napi::JsObject,
+    parent: &str,
+    children: &[&str],
+) -> napi::Result<()> {
+    if !exports.has_named_property(parent)? {
+        exports.set_named_property(parent, env.create_object()?)?;
+    }
+
+    for child in children {
+        move_namespace(exports, parent, child)?;
+    }
+    Ok(())
+}
+
 #[napi_derive::module_exports]
-fn module_exports(mut exports: napi::JsObject) -> napi::Result<()> {
+fn module_exports(mut exports: napi::JsObject, env: napi::Env) -> napi::Result<()> {
+    group_namespaces(
+        &env,
+        &mut exports,
+        "classic",
+        &[
+            "vigenere",
+            "beaufort",
+            "autokey",
+            "atbash",
+            "affine",
+            "substitution",
+            "playfair",
+            "railFence",
+            "columnar",
+            "bacon",
+        ],
+    )?;
+    move_namespace(&mut exports, "crypto", "classic")?;
+    move_namespace(&mut exports, "crypto", "crack")?;
+    move_namespace(&mut exports, "crypto", "analysis")?;
     move_namespace(&mut exports, "crypto", "base64")?;
     move_namespace(&mut exports, "crypto", "base16")?;
     move_namespace(&mut exports, "crypto", "base32")?;
+    move_namespace(&mut exports, "crypto", "base58")?;
+    move_namespace(&mut exports, "crypto", "base58check")?;
+    move_namespace(&mut exports, "crypto", "base85")?;
+    move_namespace(&mut exports, "crypto", "baseN")?;
+    move_namespace(&mut exports, "crypto", "bech32")?;
+    move_namespace(&mut exports, "crypto", "bech32m")?;
+    move_namespace(&mut exports, "crypto", "percent")?;
+    move_namespace(&mut exports, "crypto", "html")?;
+    move_namespace(&mut exports, "crypto", "quotedPrintable")?;
+    move_namespace(&mut exports, "crypto", "encodedWord")?;
     move_namespace(&mut exports, "crypto", "AES")?;
     move_namespace(&mut exports, "crypto", "RSA")?;
     move_namespace(&mut exports, "crypto", "morse")?;
+    move_namespace(&mut exports, "crypto", "password")?;
+    move_namespace(&mut exports, "crypto", "merkle")?;
+    move_namespace(&mut exports, "crypto", "manifest")?;
     Ok(())
 }
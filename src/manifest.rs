@@ -0,0 +1,269 @@
+#![allow(dead_code)]
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use napi_derive::napi;
+
+use crate::crypto::{HashAlgorithm, digest_with, hash_algorithm_name, normalize_hash_algorithm};
+use crate::file::{resolve_path, sandbox_root};
+
+const DEFAULT_MANIFEST_ALGORITHM: &str = "sha256";
+const MANIFEST_SUFFIX: &str = "SUMS";
+
+struct ManifestEntry {
+    digest: String,
+    path: String,
+}
+
+#[napi(object)]
+pub struct ManifestReport {
+    pub ok: bool,
+    pub algorithm: String,
+    pub missing: Vec<String>,
+    pub modified: Vec<String>,
+    pub extra: Vec<String>,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn io_error(context: &str, error: std::io::Error) -> napi::Error {
+    napi::Error::from_reason(format!("{context}: {error}"))
+}
+
+#[inline(always)]
+fn normalize_manifest_algorithm(algorithm: Option<String>) -> napi::Result<HashAlgorithm> {
+    let value = algorithm.unwrap_or_else(|| DEFAULT_MANIFEST_ALGORITHM.to_string());
+    normalize_hash_algorithm(&value)
+}
+
+#[inline(always)]
+fn manifest_file_name(algorithm: &HashAlgorithm) -> String {
+    format!(
+        "{}{MANIFEST_SUFFIX}",
+        hash_algorithm_name(algorithm).to_ascii_uppercase()
+    )
+}
+
+#[inline(always)]
+fn infer_manifest_algorithm(manifest_path: &str) -> Option<HashAlgorithm> {
+    let name = Path::new(manifest_path).file_name()?.to_str()?;
+    let prefix = name.strip_suffix(MANIFEST_SUFFIX)?;
+    normalize_hash_algorithm(prefix).ok()
+}
+
+#[inline(always)]
+fn join_raw_path(dir: &str, name: &str) -> String {
+    Path::new(dir).join(name).to_string_lossy().into_owned()
+}
+
+#[inline(always)]
+fn parent_raw_path(path: &str) -> String {
+    match Path::new(path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_string_lossy().into_owned(),
+        _ => ".".to_string(),
+    }
+}
+
+#[inline(always)]
+fn hex_digest(algorithm: &HashAlgorithm, path: &Path) -> napi::Result<String> {
+    let contents = fs::read(path).map_err(|error| io_error("Failed to read file", error))?;
+    Ok(hex::encode(digest_with(algorithm, &contents)))
+}
+
+fn collect_files(dir: &Path, prefix: &str, output: &mut Vec<String>) -> napi::Result<()> {
+    let entries = fs::read_dir(dir).map_err(|error| io_error("Failed to read directory", error))?;
+    for entry in entries {
+        let entry = entry.map_err(|error| io_error("Failed to iterate directory", error))?;
+        let file_type = entry
+            .file_type()
+            .map_err(|error| io_error("Failed to inspect path", error))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let relative = if prefix.is_empty() {
+            name
+        } else {
+            format!("{prefix}/{name}")
+        };
+
+        if file_type.is_dir() {
+            collect_files(&entry.path(), &relative, output)?;
+        } else if file_type.is_file() {
+            output.push(relative);
+        }
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn list_files(dir: &Path, manifest_name: &str) -> napi::Result<Vec<String>> {
+    let mut output = Vec::new();
+    collect_files(dir, "", &mut output)?;
+    output.retain(|path| path != manifest_name);
+    output.sort_unstable();
+    Ok(output)
+}
+
+#[inline(always)]
+fn escape_manifest_path(path: &str) -> Option<String> {
+    if !path.contains(['\\', '\n', '\r']) {
+        return None;
+    }
+    Some(
+        path.replace('\\', "\\\\")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r"),
+    )
+}
+
+#[inline(always)]
+fn unescape_manifest_path(path: &str) -> napi::Result<String> {
+    let mut output = String::with_capacity(path.len());
+    let mut chars = path.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            output.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => output.push('\\'),
+            Some('n') => output.push('\n'),
+            Some('r') => output.push('\r'),
+            _ => return Err(invalid_input("Manifest path contains an invalid escape")),
+        }
+    }
+    Ok(output)
+}
+
+#[inline(always)]
+fn format_manifest_line(digest: &str, path: &str) -> String {
+    match escape_manifest_path(path) {
+        Some(escaped) => format!("\\{digest}  {escaped}\n"),
+        None => format!("{digest}  {path}\n"),
+    }
+}
+
+#[inline(always)]
+fn parse_manifest_line(line: &str, number: usize) -> napi::Result<ManifestEntry> {
+    let malformed = || invalid_input(&format!("Manifest line {number} is malformed"));
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let (digest, rest) = line.split_once(' ').ok_or_else(malformed)?;
+    let path = rest
+        .strip_prefix(' ')
+        .or_else(|| rest.strip_prefix('*'))
+        .ok_or_else(malformed)?;
+    if digest.is_empty() || !digest.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(malformed());
+    }
+    if path.is_empty() {
+        return Err(malformed());
+    }
+
+    let path = if escaped {
+        unescape_manifest_path(path)?
+    } else {
+        path.to_string()
+    };
+    Ok(ManifestEntry {
+        digest: digest.to_ascii_lowercase(),
+        path: path.strip_prefix("./").unwrap_or(&path).to_string(),
+    })
+}
+
+#[inline(always)]
+fn parse_manifest(contents: &str) -> napi::Result<Vec<ManifestEntry>> {
+    let mut output = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        output.push(parse_manifest_line(line, index + 1)?);
+    }
+    Ok(output)
+}
+
+#[napi(namespace = "manifest")]
+pub fn create(dir: String, algorithm: Option<String>) -> napi::Result<String> {
+    let root = sandbox_root()?;
+    let algorithm = normalize_manifest_algorithm(algorithm)?;
+    let target = resolve_path(&root, &dir)?;
+    if !target.is_dir() {
+        return Err(invalid_input(
+            "Manifest directory must be an existing folder",
+        ));
+    }
+
+    let manifest_name = manifest_file_name(&algorithm);
+    let manifest_path = join_raw_path(&dir, &manifest_name);
+    let manifest_target = resolve_path(&root, &manifest_path)?;
+
+    let mut contents = String::new();
+    for path in list_files(&target, &manifest_name)? {
+        let file_target = resolve_path(&root, &join_raw_path(&dir, &path))?;
+        let digest = hex_digest(&algorithm, &file_target)?;
+        contents.push_str(&format_manifest_line(&digest, &path));
+    }
+
+    fs::write(&manifest_target, contents)
+        .map_err(|error| io_error("Failed to write manifest", error))?;
+    Ok(manifest_path)
+}
+
+#[napi(namespace = "manifest")]
+pub fn verify(manifest_path: String, algorithm: Option<String>) -> napi::Result<ManifestReport> {
+    let root = sandbox_root()?;
+    let algorithm = match algorithm {
+        Some(value) => normalize_hash_algorithm(&value)?,
+        None => match infer_manifest_algorithm(&manifest_path) {
+            Some(inferred) => inferred,
+            None => normalize_manifest_algorithm(None)?,
+        },
+    };
+    let manifest_target = resolve_path(&root, &manifest_path)?;
+    let contents = fs::read_to_string(&manifest_target)
+        .map_err(|error| io_error("Failed to read manifest", error))?;
+    let entries = parse_manifest(&contents)?;
+
+    let dir = parent_raw_path(&manifest_path);
+    let target = resolve_path(&root, &dir)?;
+    let manifest_name = Path::new(&manifest_path)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let mut listed = BTreeSet::new();
+    let mut missing = Vec::new();
+    let mut modified = Vec::new();
+    for entry in entries {
+        if !listed.insert(entry.path.clone()) {
+            continue;
+        }
+        let file_target = resolve_path(&root, &join_raw_path(&dir, &entry.path))?;
+        if !file_target.is_file() {
+            missing.push(entry.path);
+            continue;
+        }
+        if hex_digest(&algorithm, &file_target)? != entry.digest {
+            modified.push(entry.path);
+        }
+    }
+
+    let extra: Vec<String> = list_files(&target, &manifest_name)?
+        .into_iter()
+        .filter(|path| !listed.contains(path))
+        .collect();
+
+    Ok(ManifestReport {
+        ok: missing.is_empty() && modified.is_empty() && extra.is_empty(),
+        algorithm: hash_algorithm_name(&algorithm).to_string(),
+        missing,
+        modified,
+        extra,
+    })
+}
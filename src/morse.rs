@@ -0,0 +1,1216 @@
+#![allow(dead_code)]
+use std::collections::HashMap;
+
+use napi::bindgen_prelude::{Buffer, Either, Float32Array};
+use napi_derive::napi;
+
+use crate::file::{create_file, sandbox_root};
+
+const MORSE_WORD_SEPARATOR: &str = "/";
+const MORSE_PROSIGN_OPEN: char = '<';
+const MORSE_PROSIGN_CLOSE: char = '>';
+const MORSE_PROSIGN_MAX_CHARS: usize = 3;
+const WABUN_DAKUTEN: char = '\u{309b}';
+const WABUN_HANDAKUTEN: char = '\u{309c}';
+const WABUN_DAKUTEN_BASES: &str = "カキクケコサシスセソタチツテトハヒフヘホ";
+const WABUN_HANDAKUTEN_BASES: &str = "ハヒフヘホ";
+const WABUN_SMALL_KANA: &str = "ァィゥェォッャュョヮ";
+const HIRAGANA_START: u32 = 0x3041;
+const HIRAGANA_END: u32 = 0x3096;
+const HIRAGANA_KATAKANA_OFFSET: u32 = 0x60;
+const PARIS_UNIT_SECONDS: f64 = 1.2;
+const FARNSWORTH_PARIS_SECONDS: f64 = 37.2;
+const DEFAULT_AUDIO_WPM: f64 = 20.0;
+const DEFAULT_AUDIO_FREQUENCY: f64 = 600.0;
+const DEFAULT_AUDIO_SAMPLE_RATE: u32 = 44_100;
+const DEFAULT_AUDIO_VOLUME: f64 = 0.8;
+const MIN_AUDIO_SAMPLE_RATE: u32 = 8_000;
+const MAX_AUDIO_SAMPLE_RATE: u32 = 192_000;
+const MAX_AUDIO_WPM: f64 = 100.0;
+const MAX_AUDIO_SECONDS: f64 = 600.0;
+const AUDIO_RAMP_SECONDS: f64 = 0.005;
+const WAV_HEADER_BYTES: usize = 44;
+const WAV_BITS_PER_SAMPLE: u16 = 16;
+const WAV_FORMAT_PCM: u16 = 1;
+const WAV_FORMAT_FLOAT: u16 = 3;
+const TIMING_CLUSTER_RATIO: f64 = 1.7;
+const DAH_UNITS: f64 = 3.0;
+const ELEMENT_GAP_LIMIT_UNITS: f64 = 2.0;
+const WORD_GAP_LIMIT_UNITS: f64 = 5.0;
+const TIMING_BIAS_TOLERANCE: f64 = 0.5;
+const DETECT_MIN_CYCLES: f64 = 2.0;
+const DETECT_MIN_WINDOW_SAMPLES: usize = 8;
+const DETECT_UNIT_FRACTION: f64 = 0.5;
+const DETECT_HOPS_PER_WINDOW: usize = 4;
+const SCAN_ENVELOPE_SECONDS: f64 = 0.01;
+const SCAN_MAX_SECONDS: f64 = 0.25;
+const SCAN_MIN_FREQUENCY: f64 = 100.0;
+const SCAN_MAX_FREQUENCY: f64 = 4_000.0;
+const SCAN_STEP_FREQUENCY: f64 = 10.0;
+const SCAN_FINE_STEP_FREQUENCY: f64 = 1.0;
+const TONE_MIN_AMPLITUDE: f64 = 0.001;
+const TONE_THRESHOLD: f64 = 0.5;
+const NOISE_FLOOR_PERCENTILE: f64 = 0.1;
+
+const MORSE_ITU_TABLE: &[(char, &str)] = &[
+    ('a', ".-"),
+    ('b', "-..."),
+    ('c', "-.-."),
+    ('d', "-.."),
+    ('e', "."),
+    ('f', "..-."),
+    ('g', "--."),
+    ('h', "...."),
+    ('i', ".."),
+    ('j', ".---"),
+    ('k', "-.-"),
+    ('l', ".-.."),
+    ('m', "--"),
+    ('n', "-."),
+    ('o', "---"),
+    ('p', ".--."),
+    ('q', "--.-"),
+    ('r', ".-."),
+    ('s', "..."),
+    ('t', "-"),
+    ('u', "..-"),
+    ('v', "...-"),
+    ('w', ".--"),
+    ('x', "-..-"),
+    ('y', "-.--"),
+    ('z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('!', "-.-.--"),
+    ('\'', ".----."),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
+
+const MORSE_LATIN_TABLE: &[(char, &str)] = &[
+    ('à', ".--.-"),
+    ('å', ".--.-"),
+    ('ä', ".-.-"),
+    ('æ', ".-.-"),
+    ('ą', ".-.-"),
+    ('ç', "-.-.."),
+    ('ć', "-.-.."),
+    ('ĉ', "-.-.."),
+    ('ð', "..--."),
+    ('é', "..-.."),
+    ('ę', "..-.."),
+    ('è', ".-..-"),
+    ('ł', ".-..-"),
+    ('ĝ', "--.-."),
+    ('ĥ', "----"),
+    ('ĵ', ".---."),
+    ('ñ', "--.--"),
+    ('ń', "--.--"),
+    ('ö', "---."),
+    ('ó', "---."),
+    ('ø', "---."),
+    ('ś', "...-..."),
+    ('ŝ', "...-."),
+    ('š', "----"),
+    ('þ', ".--.."),
+    ('ü', "..--"),
+    ('ŭ', "..--"),
+    ('ź', "--..-."),
+    ('ż', "--..-"),
+];
+
+const MORSE_CYRILLIC_TABLE: &[(char, &str)] = &[
+    ('а', ".-"),
+    ('б', "-..."),
+    ('в', ".--"),
+    ('г', "--."),
+    ('д', "-.."),
+    ('е', "."),
+    ('ё', "."),
+    ('ж', "...-"),
+    ('з', "--.."),
+    ('и', ".."),
+    ('й', ".---"),
+    ('к', "-.-"),
+    ('л', ".-.."),
+    ('м', "--"),
+    ('н', "-."),
+    ('о', "---"),
+    ('п', ".--."),
+    ('р', ".-."),
+    ('с', "..."),
+    ('т', "-"),
+    ('у', "..-"),
+    ('ф', "..-."),
+    ('х', "...."),
+    ('ц', "-.-."),
+    ('ч', "---."),
+    ('ш', "----"),
+    ('щ', "--.-"),
+    ('ъ', "--.--"),
+    ('ы', "-.--"),
+    ('ь', "-..-"),
+    ('э', "..-.."),
+    ('ю', "..--"),
+    ('я', ".-.-"),
+];
+
+const MORSE_GREEK_TABLE: &[(char, &str)] = &[
+    ('α', ".-"),
+    ('β', "-..."),
+    ('γ', "--."),
+    ('δ', "-.."),
+    ('ε', "."),
+    ('ζ', "--.."),
+    ('η', "...."),
+    ('θ', "-.-."),
+    ('ι', ".."),
+    ('κ', "-.-"),
+    ('λ', ".-.."),
+    ('μ', "--"),
+    ('ν', "-."),
+    ('ξ', "-..-"),
+    ('ο', "---"),
+    ('π', ".--."),
+    ('ρ', ".-."),
+    ('σ', "..."),
+    ('ς', "..."),
+    ('τ', "-"),
+    ('υ', "-.--"),
+    ('φ', "..-."),
+    ('χ', "----"),
+    ('ψ', "--.-"),
+    ('ω', ".--"),
+    ('ά', ".-"),
+    ('έ', "."),
+    ('ή', "...."),
+    ('ί', ".."),
+    ('ϊ', ".."),
+    ('ΐ', ".."),
+    ('ό', "---"),
+    ('ύ', "-.--"),
+    ('ϋ', "-.--"),
+    ('ΰ', "-.--"),
+    ('ώ', ".--"),
+];
+
+const MORSE_WABUN_TABLE: &[(char, &str)] = &[
+    ('ア', "--.--"),
+    ('イ', ".-"),
+    ('ウ', "..-"),
+    ('エ', "-.---"),
+    ('オ', ".-..."),
+    ('カ', ".-.."),
+    ('キ', "-.-.."),
+    ('ク', "...-"),
+    ('ケ', "-.--"),
+    ('コ', "----"),
+    ('サ', "-.-.-"),
+    ('シ', "--.-."),
+    ('ス', "---.-"),
+    ('セ', ".---."),
+    ('ソ', "---."),
+    ('タ', "-."),
+    ('チ', "..-."),
+    ('ツ', ".--."),
+    ('テ', ".-.--"),
+    ('ト', "..-.."),
+    ('ナ', ".-."),
+    ('ニ', "-.-."),
+    ('ヌ', "...."),
+    ('ネ', "--.-"),
+    ('ノ', "..--"),
+    ('ハ', "-..."),
+    ('ヒ', "--..-"),
+    ('フ', "--.."),
+    ('ヘ', "."),
+    ('ホ', "-.."),
+    ('マ', "-..-"),
+    ('ミ', "..-.-"),
+    ('ム', "-"),
+    ('メ', "-...-"),
+    ('モ', "-..-."),
+    ('ヤ', ".--"),
+    ('ユ', "-..--"),
+    ('ヨ', "--"),
+    ('ラ', "..."),
+    ('リ', "--."),
+    ('ル', "-.--."),
+    ('レ', "---"),
+    ('ロ', ".-.-"),
+    ('ワ', "-.-"),
+    ('ヰ', ".-..-"),
+    ('ヱ', ".--.."),
+    ('ヲ', ".---"),
+    ('ン', ".-.-."),
+    ('゛', ".."),
+    ('゜', "..--."),
+    ('ー', ".--.-"),
+    ('、', ".-.-.-"),
+    ('」', ".-.-.."),
+];
+
+const MORSE_PROSIGNS: &[(&str, &str)] = &[
+    ("AR", ".-.-."),
+    ("AS", ".-..."),
+    ("BK", "-...-.-"),
+    ("BT", "-...-"),
+    ("CL", "-.-..-.."),
+    ("CT", "-.-.-"),
+    ("HH", "........"),
+    ("KN", "-.--."),
+    ("SK", "...-.-"),
+    ("SN", "...-."),
+    ("SOS", "...---..."),
+];
+
+type MorseTableInput = Either<String, HashMap<String, String>>;
+
+enum AudioFormat {
+    Wav,
+    Pcm,
+}
+
+struct MorseTiming {
+    unit: f64,
+    letter_gap: f64,
+    word_gap: f64,
+}
+
+enum MorseScript {
+    Latin,
+    Wabun,
+}
+
+struct MorseCodec {
+    entries: Vec<(char, String)>,
+    script: MorseScript,
+    strict: bool,
+    prosigns: bool,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MorseOptions {
+    #[napi(ts_type = "string | Record<string, string>")]
+    pub table: Option<MorseTableInput>,
+    pub strict: Option<bool>,
+    pub prosigns: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MorseAudioOptions {
+    pub wpm: Option<f64>,
+    pub farnsworth: Option<f64>,
+    pub frequency: Option<f64>,
+    #[napi(js_name = "sampleRate")]
+    pub sample_rate: Option<u32>,
+    pub volume: Option<f64>,
+    pub format: Option<String>,
+    pub path: Option<String>,
+    #[napi(ts_type = "string | Record<string, string>")]
+    pub table: Option<MorseTableInput>,
+    pub strict: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct MorseAudioDecodeOptions {
+    pub frequency: Option<f64>,
+    #[napi(ts_type = "string | Record<string, string>")]
+    pub table: Option<MorseTableInput>,
+    pub strict: Option<bool>,
+    pub prosigns: Option<bool>,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn table_entries(table: &[(char, &str)]) -> Vec<(char, String)> {
+    table
+        .iter()
+        .map(|(ch, code)| (*ch, code.to_string()))
+        .collect()
+}
+
+#[inline(always)]
+fn itu_symbol_entries() -> Vec<(char, String)> {
+    MORSE_ITU_TABLE
+        .iter()
+        .filter(|(ch, _)| !ch.is_ascii_alphabetic())
+        .map(|(ch, code)| (*ch, code.to_string()))
+        .collect()
+}
+
+#[inline(always)]
+fn is_morse_code(code: &str) -> bool {
+    !code.is_empty() && code.chars().all(|ch| matches!(ch, '.' | '-'))
+}
+
+#[inline(always)]
+fn custom_morse_entries(table: HashMap<String, String>) -> napi::Result<Vec<(char, String)>> {
+    let mut entries = Vec::with_capacity(table.len());
+    for (key, code) in table {
+        let mut chars = key.chars();
+        let (Some(ch), None) = (chars.next(), chars.next()) else {
+            return Err(invalid_input(
+                "Custom Morse table keys must be single characters",
+            ));
+        };
+        if !is_morse_code(&code) {
+            return Err(invalid_input(
+                "Custom Morse codes must contain only '.' and '-'",
+            ));
+        }
+        entries.push((ch, code));
+    }
+    entries.sort_unstable();
+    Ok(entries)
+}
+
+#[inline(always)]
+fn normalize_morse_table(
+    table: Option<MorseTableInput>,
+) -> napi::Result<(Vec<(char, String)>, MorseScript)> {
+    let name = match table {
+        None => return Ok((table_entries(MORSE_ITU_TABLE), MorseScript::Latin)),
+        Some(Either::B(custom)) => {
+            let mut entries = custom_morse_entries(custom)?;
+            entries.extend(table_entries(MORSE_ITU_TABLE));
+            return Ok((entries, MorseScript::Latin));
+        }
+        Some(Either::A(name)) => name,
+    };
+
+    let (entries, script) = match name.to_ascii_lowercase().as_str() {
+        "itu" | "international" => (table_entries(MORSE_ITU_TABLE), MorseScript::Latin),
+        "latin" => {
+            let mut entries = table_entries(MORSE_ITU_TABLE);
+            entries.extend(table_entries(MORSE_LATIN_TABLE));
+            (entries, MorseScript::Latin)
+        }
+        "cyrillic" | "russian" => {
+            let mut entries = table_entries(MORSE_CYRILLIC_TABLE);
+            entries.extend(itu_symbol_entries());
+            (entries, MorseScript::Latin)
+        }
+        "greek" => {
+            let mut entries = table_entries(MORSE_GREEK_TABLE);
+            entries.extend(itu_symbol_entries());
+            (entries, MorseScript::Latin)
+        }
+        "wabun" | "japanese" => {
+            let mut entries = table_entries(MORSE_WABUN_TABLE);
+            entries.extend(itu_symbol_entries());
+            (entries, MorseScript::Wabun)
+        }
+        _ => {
+            return Err(invalid_input(
+                "Morse table must be one of: itu, latin, cyrillic, greek, wabun",
+            ));
+        }
+    };
+    Ok((entries, script))
+}
+
+#[inline(always)]
+fn morse_codec(options: Option<MorseOptions>) -> napi::Result<MorseCodec> {
+    let options = options.unwrap_or_default();
+    let (entries, script) = normalize_morse_table(options.table)?;
+    Ok(MorseCodec {
+        entries,
+        script,
+        strict: options.strict.unwrap_or(false),
+        prosigns: options.prosigns.unwrap_or(false),
+    })
+}
+
+#[inline(always)]
+fn morse_code_for(codec: &MorseCodec, ch: char) -> Option<&str> {
+    let lower = ch.to_lowercase().next().unwrap_or(ch);
+    codec
+        .entries
+        .iter()
+        .find(|(symbol, _)| *symbol == ch)
+        .or_else(|| codec.entries.iter().find(|(symbol, _)| *symbol == lower))
+        .map(|(_, code)| code.as_str())
+}
+
+#[inline(always)]
+fn morse_char_for(codec: &MorseCodec, code: &str) -> Option<char> {
+    codec
+        .entries
+        .iter()
+        .find(|(_, symbol)| symbol == code)
+        .map(|(ch, _)| *ch)
+}
+
+#[inline(always)]
+fn prosign_code_for(name: &str) -> Option<&'static str> {
+    MORSE_PROSIGNS
+        .iter()
+        .find(|(prosign, _)| prosign.eq_ignore_ascii_case(name))
+        .map(|(_, code)| *code)
+}
+
+#[inline(always)]
+fn prosign_name_for(code: &str) -> Option<&'static str> {
+    MORSE_PROSIGNS
+        .iter()
+        .find(|(_, symbol)| *symbol == code)
+        .map(|(name, _)| *name)
+}
+
+#[inline(always)]
+fn read_prosign(chars: &[char], start: usize) -> Option<(&'static str, usize)> {
+    let end = chars
+        .iter()
+        .skip(start + 1)
+        .take(MORSE_PROSIGN_MAX_CHARS + 1)
+        .position(|ch| *ch == MORSE_PROSIGN_CLOSE)?;
+    let name: String = chars[start + 1..start + 1 + end].iter().collect();
+    let code = prosign_code_for(&name)?;
+    Some((code, end + 2))
+}
+
+#[inline(always)]
+fn shift_char(ch: char, offset: u32) -> char {
+    char::from_u32(ch as u32 + offset).unwrap_or(ch)
+}
+
+#[inline(always)]
+fn wabun_expand(input: &str) -> Vec<char> {
+    let mut output = Vec::with_capacity(input.len());
+    for ch in input.chars() {
+        let ch = if (HIRAGANA_START..=HIRAGANA_END).contains(&(ch as u32)) {
+            shift_char(ch, HIRAGANA_KATAKANA_OFFSET)
+        } else {
+            ch
+        };
+        if WABUN_SMALL_KANA.contains(ch) {
+            output.push(shift_char(ch, 1));
+            continue;
+        }
+        let voiced = WABUN_DAKUTEN_BASES
+            .chars()
+            .find(|base| shift_char(*base, 1) == ch);
+        let semi_voiced = WABUN_HANDAKUTEN_BASES
+            .chars()
+            .find(|base| shift_char(*base, 2) == ch);
+        match (voiced, semi_voiced) {
+            (Some(base), _) => output.extend([base, WABUN_DAKUTEN]),
+            (_, Some(base)) => output.extend([base, WABUN_HANDAKUTEN]),
+            _ => output.push(ch),
+        }
+    }
+    output
+}
+
+#[inline(always)]
+fn wabun_compose(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for ch in input.chars() {
+        let base = output.chars().last();
+        let composed = match (base, ch) {
+            (Some(base), WABUN_DAKUTEN) if WABUN_DAKUTEN_BASES.contains(base) => {
+                Some(shift_char(base, 1))
+            }
+            (Some(base), WABUN_HANDAKUTEN) if WABUN_HANDAKUTEN_BASES.contains(base) => {
+                Some(shift_char(base, 2))
+            }
+            _ => None,
+        };
+        match composed {
+            Some(composed) => {
+                output.pop();
+                output.push(composed);
+            }
+            None => output.push(ch),
+        }
+    }
+    output
+}
+
+#[inline(always)]
+fn should_insert_space(output: &str) -> bool {
+    !output.is_empty() && !output.ends_with(' ')
+}
+
+fn morse_encode_with(input: &str, codec: &MorseCodec) -> napi::Result<String> {
+    let chars = match codec.script {
+        MorseScript::Wabun => wabun_expand(input),
+        MorseScript::Latin => input.chars().collect(),
+    };
+
+    let mut output = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let ch = chars[index];
+        if ch.is_whitespace() {
+            output.push(MORSE_WORD_SEPARATOR.to_string());
+            index += 1;
+            continue;
+        }
+        if ch == MORSE_PROSIGN_OPEN
+            && let Some((code, width)) = read_prosign(&chars, index)
+        {
+            output.push(code.to_string());
+            index += width;
+            continue;
+        }
+        match morse_code_for(codec, ch) {
+            Some(code) => output.push(code.to_string()),
+            None if codec.strict => {
+                return Err(invalid_input(&format!(
+                    "Cannot encode character {ch:?} at position {index} in Morse"
+                )));
+            }
+            None => {}
+        }
+        index += 1;
+    }
+    Ok(output.join(" "))
+}
+
+fn morse_decode_with(input: &str, codec: &MorseCodec) -> napi::Result<String> {
+    let mut output = String::new();
+    for token in input.split_whitespace() {
+        if token == MORSE_WORD_SEPARATOR {
+            if should_insert_space(&output) {
+                output.push(' ');
+            }
+            continue;
+        }
+        let prosign = prosign_name_for(token);
+        let ch = if codec.prosigns && prosign.is_some() {
+            None
+        } else {
+            morse_char_for(codec, token)
+        };
+        match (ch, prosign) {
+            (Some(ch), _) => output.push(ch),
+            (None, Some(name)) => {
+                output.push(MORSE_PROSIGN_OPEN);
+                output.push_str(name);
+                output.push(MORSE_PROSIGN_CLOSE);
+            }
+            (None, None) if codec.strict => {
+                return Err(invalid_input(&format!("Unknown Morse code '{token}'")));
+            }
+            (None, None) => {}
+        }
+    }
+    Ok(match codec.script {
+        MorseScript::Wabun => wabun_compose(&output),
+        MorseScript::Latin => output,
+    })
+}
+
+#[napi(namespace = "morse", js_name = "encode")]
+pub fn morse_encode(input: String, options: Option<MorseOptions>) -> napi::Result<String> {
+    let codec = morse_codec(options)?;
+    if input.is_empty() {
+        return Ok(input);
+    }
+    morse_encode_with(&input, &codec)
+}
+
+#[napi(namespace = "morse", js_name = "decode")]
+pub fn morse_decode(input: String, options: Option<MorseOptions>) -> napi::Result<String> {
+    let codec = morse_codec(options)?;
+    if input.trim().is_empty() {
+        return Ok(String::new());
+    }
+    morse_decode_with(&input, &codec)
+}
+
+#[inline(always)]
+fn normalize_audio_format(format: Option<String>) -> napi::Result<AudioFormat> {
+    match format.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("wav") => Ok(AudioFormat::Wav),
+        Some("pcm") | Some("float32") => Ok(AudioFormat::Pcm),
+        _ => Err(invalid_input("Morse audio format must be one of: wav, pcm")),
+    }
+}
+
+#[inline(always)]
+fn morse_timing(wpm: f64, farnsworth: Option<f64>) -> napi::Result<MorseTiming> {
+    if !(wpm > 0.0 && wpm <= MAX_AUDIO_WPM) {
+        return Err(invalid_input("wpm must be between 0 and 100"));
+    }
+    let unit = PARIS_UNIT_SECONDS / wpm;
+    let Some(effective) = farnsworth else {
+        return Ok(MorseTiming {
+            unit,
+            letter_gap: 3.0 * unit,
+            word_gap: 7.0 * unit,
+        });
+    };
+    if !(effective > 0.0 && effective <= wpm) {
+        return Err(invalid_input(
+            "farnsworth must be greater than 0 and at most wpm",
+        ));
+    }
+    let delay = (60.0 * wpm - FARNSWORTH_PARIS_SECONDS * effective) / (effective * wpm);
+    Ok(MorseTiming {
+        unit,
+        letter_gap: 3.0 * delay / 19.0,
+        word_gap: 7.0 * delay / 19.0,
+    })
+}
+
+#[inline(always)]
+fn morse_segments(code: &str, timing: &MorseTiming) -> Vec<(bool, f64)> {
+    let mut segments = Vec::new();
+    let mut gap = None;
+    for token in code.split_whitespace() {
+        if token == MORSE_WORD_SEPARATOR {
+            if !segments.is_empty() {
+                gap = Some(timing.word_gap);
+            }
+            continue;
+        }
+        if !segments.is_empty() {
+            segments.push((false, gap.take().unwrap_or(timing.letter_gap)));
+        }
+        for (index, element) in token.chars().enumerate() {
+            if index > 0 {
+                segments.push((false, timing.unit));
+            }
+            let length = if element == '-' { 3.0 } else { 1.0 };
+            segments.push((true, length * timing.unit));
+        }
+    }
+    segments
+}
+
+#[inline(always)]
+fn render_tone(samples: &mut [f32], start: usize, sample_rate: f64, frequency: f64, volume: f64) {
+    let ramp = ((AUDIO_RAMP_SECONDS * sample_rate) as usize).min(samples.len() / 2);
+    let length = samples.len();
+    for (offset, sample) in samples.iter_mut().enumerate() {
+        let edge = offset.min(length - 1 - offset);
+        let envelope = if edge < ramp {
+            0.5 - 0.5 * (std::f64::consts::PI * edge as f64 / ramp as f64).cos()
+        } else {
+            1.0
+        };
+        let phase = std::f64::consts::TAU * frequency * (start + offset) as f64 / sample_rate;
+        *sample = (volume * envelope * phase.sin()) as f32;
+    }
+}
+
+#[inline(always)]
+fn render_segments(
+    segments: &[(bool, f64)],
+    sample_rate: u32,
+    frequency: f64,
+    volume: f64,
+) -> Vec<f32> {
+    let rate = sample_rate as f64;
+    let total: f64 = segments.iter().map(|(_, duration)| duration).sum();
+    let mut samples = vec![0.0_f32; (total * rate).round() as usize];
+    let mut elapsed = 0.0;
+    for (tone, duration) in segments {
+        let start = (elapsed * rate).round() as usize;
+        elapsed += duration;
+        let end = ((elapsed * rate).round() as usize).min(samples.len());
+        if *tone {
+            render_tone(&mut samples[start..end], start, rate, frequency, volume);
+        }
+    }
+    samples
+}
+
+#[inline(always)]
+fn wav_bytes(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let bytes_per_sample = (WAV_BITS_PER_SAMPLE / 8) as u32;
+    let data_len = samples.len() as u32 * bytes_per_sample;
+    let mut output = Vec::with_capacity(WAV_HEADER_BYTES + data_len as usize);
+    output.extend_from_slice(b"RIFF");
+    output.extend_from_slice(&(36 + data_len).to_le_bytes());
+    output.extend_from_slice(b"WAVEfmt ");
+    output.extend_from_slice(&16_u32.to_le_bytes());
+    output.extend_from_slice(&1_u16.to_le_bytes());
+    output.extend_from_slice(&1_u16.to_le_bytes());
+    output.extend_from_slice(&sample_rate.to_le_bytes());
+    output.extend_from_slice(&(sample_rate * bytes_per_sample).to_le_bytes());
+    output.extend_from_slice(&(bytes_per_sample as u16).to_le_bytes());
+    output.extend_from_slice(&WAV_BITS_PER_SAMPLE.to_le_bytes());
+    output.extend_from_slice(b"data");
+    output.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16;
+        output.extend_from_slice(&value.to_le_bytes());
+    }
+    output
+}
+
+#[napi(namespace = "morse", js_name = "toAudio")]
+pub fn morse_to_audio(
+    input: String,
+    options: Option<MorseAudioOptions>,
+) -> napi::Result<Either<Buffer, Float32Array>> {
+    let options = options.unwrap_or_default();
+    let format = normalize_audio_format(options.format)?;
+    let timing = morse_timing(options.wpm.unwrap_or(DEFAULT_AUDIO_WPM), options.farnsworth)?;
+    let sample_rate = options.sample_rate.unwrap_or(DEFAULT_AUDIO_SAMPLE_RATE);
+    if !(MIN_AUDIO_SAMPLE_RATE..=MAX_AUDIO_SAMPLE_RATE).contains(&sample_rate) {
+        return Err(invalid_input("sampleRate must be between 8000 and 192000"));
+    }
+    let frequency = options.frequency.unwrap_or(DEFAULT_AUDIO_FREQUENCY);
+    if !(frequency > 0.0 && frequency < sample_rate as f64 / 2.0) {
+        return Err(invalid_input(
+            "frequency must be greater than 0 and below half the sampleRate",
+        ));
+    }
+    let volume = options.volume.unwrap_or(DEFAULT_AUDIO_VOLUME);
+    if !(0.0..=1.0).contains(&volume) {
+        return Err(invalid_input("volume must be between 0 and 1"));
+    }
+    if options.path.is_some() && matches!(format, AudioFormat::Pcm) {
+        return Err(invalid_input("Morse audio path requires the wav format"));
+    }
+
+    let codec = morse_codec(Some(MorseOptions {
+        table: options.table,
+        strict: options.strict,
+        prosigns: None,
+    }))?;
+    let code = morse_encode_with(&input, &codec)?;
+    let segments = morse_segments(&code, &timing);
+    let duration: f64 = segments.iter().map(|(_, duration)| duration).sum();
+    if duration > MAX_AUDIO_SECONDS {
+        return Err(invalid_input(
+            "Morse audio must be at most 600 seconds long",
+        ));
+    }
+    let samples = render_segments(&segments, sample_rate, frequency, volume);
+
+    match format {
+        AudioFormat::Pcm => Ok(Either::B(Float32Array::new(samples))),
+        AudioFormat::Wav => {
+            let wav = wav_bytes(&samples, sample_rate);
+            if let Some(path) = options.path {
+                let root = sandbox_root()?;
+                create_file(&root, &path, &wav)?;
+            }
+            Ok(Either::A(wav.into()))
+        }
+    }
+}
+
+#[inline(always)]
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+#[inline(always)]
+fn split_clusters(values: &[f64]) -> Option<f64> {
+    let mut sorted: Vec<f64> = values.iter().map(|value| value.ln()).collect();
+    sorted.sort_by(f64::total_cmp);
+    let mut best: Option<(f64, usize)> = None;
+    for split in 1..sorted.len() {
+        let (low, high) = sorted.split_at(split);
+        let (low_mean, high_mean) = (mean(low), mean(high));
+        let spread: f64 = low
+            .iter()
+            .map(|value| (value - low_mean).powi(2))
+            .sum::<f64>()
+            + high
+                .iter()
+                .map(|value| (value - high_mean).powi(2))
+                .sum::<f64>();
+        if best.is_none_or(|(current, _)| spread < current) {
+            best = Some((spread, split));
+        }
+    }
+    let (_, split) = best?;
+    let (low, high) = sorted.split_at(split);
+    if (mean(high) - mean(low)).exp() < TIMING_CLUSTER_RATIO {
+        return None;
+    }
+    Some(((low[low.len() - 1] + high[0]) / 2.0).exp())
+}
+
+#[inline(always)]
+fn estimate_unit(ons: &[f64], offs: &[f64]) -> f64 {
+    if let Some(threshold) = split_clusters(ons) {
+        let units: Vec<f64> = ons
+            .iter()
+            .map(|on| if *on < threshold { *on } else { on / DAH_UNITS })
+            .collect();
+        return mean(&units);
+    }
+    let on = mean(ons);
+    if offs.is_empty() {
+        return on;
+    }
+    let gap = match split_clusters(offs) {
+        Some(threshold) => {
+            let short: Vec<f64> = offs
+                .iter()
+                .copied()
+                .filter(|off| *off < threshold)
+                .collect();
+            mean(&short)
+        }
+        None => mean(offs),
+    };
+    if on / gap >= ELEMENT_GAP_LIMIT_UNITS {
+        on / DAH_UNITS
+    } else {
+        on
+    }
+}
+
+#[inline(always)]
+fn timing_bias(ons: &[f64], offs: &[f64]) -> f64 {
+    let Some(threshold) = split_clusters(ons) else {
+        return 0.0;
+    };
+    let Some(shortest) = offs.iter().copied().min_by(f64::total_cmp) else {
+        return 0.0;
+    };
+    let dots: Vec<f64> = ons.iter().copied().filter(|on| *on < threshold).collect();
+    let dahs: Vec<f64> = ons.iter().copied().filter(|on| *on >= threshold).collect();
+    let gaps: Vec<f64> = offs
+        .iter()
+        .copied()
+        .filter(|off| *off < shortest * TIMING_CLUSTER_RATIO)
+        .collect();
+    let (dot, dah, gap) = (mean(&dots), mean(&dahs), mean(&gaps));
+    let span = dah - dot;
+    if (gap + dot - span).abs() > TIMING_BIAS_TOLERANCE * span {
+        return 0.0;
+    }
+    (gap - dot) / 2.0
+}
+
+#[inline(always)]
+fn split_timings(timings: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let ons = timings
+        .iter()
+        .step_by(2)
+        .copied()
+        .filter(|on| *on > 0.0)
+        .collect();
+    let offs = timings
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .copied()
+        .filter(|off| *off > 0.0)
+        .collect();
+    (ons, offs)
+}
+
+#[inline(always)]
+fn word_gap_threshold(offs: &[f64], unit: f64) -> f64 {
+    let gaps: Vec<f64> = offs
+        .iter()
+        .copied()
+        .filter(|off| *off >= ELEMENT_GAP_LIMIT_UNITS * unit)
+        .collect();
+    split_clusters(&gaps).unwrap_or(WORD_GAP_LIMIT_UNITS * unit)
+}
+
+fn timings_to_code(timings: &[f64]) -> napi::Result<String> {
+    if timings
+        .iter()
+        .any(|timing| !timing.is_finite() || *timing < 0.0)
+    {
+        return Err(invalid_input(
+            "Morse timings must be non-negative finite numbers",
+        ));
+    }
+    let (ons, offs) = split_timings(timings);
+    if ons.is_empty() {
+        return Ok(String::new());
+    }
+    let bias = timing_bias(&ons, &offs);
+    let timings: Vec<f64> = timings
+        .iter()
+        .enumerate()
+        .map(|(index, timing)| match (*timing > 0.0, index % 2) {
+            (false, _) => *timing,
+            (true, 0) => timing + bias,
+            (true, _) => timing - bias,
+        })
+        .collect();
+    let (ons, offs) = split_timings(&timings);
+    let unit = estimate_unit(&ons, &offs);
+    let word_gap = word_gap_threshold(&offs, unit);
+
+    let mut code = String::new();
+    for (index, timing) in timings.iter().enumerate() {
+        if *timing <= 0.0 {
+            continue;
+        }
+        if index % 2 == 0 {
+            code.push(if *timing < ELEMENT_GAP_LIMIT_UNITS * unit {
+                '.'
+            } else {
+                '-'
+            });
+        } else if index + 1 < timings.len() {
+            if *timing >= word_gap {
+                code.push_str(" / ");
+            } else if *timing >= ELEMENT_GAP_LIMIT_UNITS * unit {
+                code.push(' ');
+            }
+        }
+    }
+    Ok(code)
+}
+
+#[inline(always)]
+fn goertzel_amplitude(samples: &[f32], frequency: f64, sample_rate: f64) -> f64 {
+    let coefficient = 2.0 * (std::f64::consts::TAU * frequency / sample_rate).cos();
+    let (mut previous, mut before) = (0.0, 0.0);
+    for sample in samples {
+        let current = *sample as f64 + coefficient * previous - before;
+        before = previous;
+        previous = current;
+    }
+    let power = previous * previous + before * before - coefficient * previous * before;
+    2.0 * power.max(0.0).sqrt() / samples.len() as f64
+}
+
+#[inline(always)]
+fn scan_tone_frequency(
+    samples: &[f32],
+    sample_rate: f64,
+    from: f64,
+    to: f64,
+    step: f64,
+) -> (f64, f64) {
+    let mut best = (from, 0.0);
+    let mut frequency = from;
+    while frequency <= to {
+        let amplitude = goertzel_amplitude(samples, frequency, sample_rate);
+        if amplitude > best.1 {
+            best = (frequency, amplitude);
+        }
+        frequency += step;
+    }
+    best
+}
+
+#[inline(always)]
+fn estimate_tone_frequency(samples: &[f32], sample_rate: f64) -> f64 {
+    let window = ((SCAN_ENVELOPE_SECONDS * sample_rate) as usize).max(1);
+    let energies: Vec<f64> = samples
+        .chunks(window)
+        .map(|chunk| chunk.iter().map(|x| (*x as f64).powi(2)).sum())
+        .collect();
+    let peak = energies.iter().copied().fold(0.0, f64::max);
+    let (mut start, mut longest) = (0, (0, 0));
+    for (index, energy) in energies.iter().enumerate() {
+        if *energy < peak * TONE_THRESHOLD {
+            start = index + 1;
+        } else if index + 1 - start > longest.1 {
+            longest = (start, index + 1 - start);
+        }
+    }
+    let from = longest.0 * window;
+    let to = ((longest.0 + longest.1) * window)
+        .min(from + (SCAN_MAX_SECONDS * sample_rate) as usize)
+        .min(samples.len());
+    let tone = &samples[from..to];
+
+    let max_frequency = SCAN_MAX_FREQUENCY.min(sample_rate / 2.0 - SCAN_STEP_FREQUENCY);
+    let (coarse, _) = scan_tone_frequency(
+        tone,
+        sample_rate,
+        SCAN_MIN_FREQUENCY,
+        max_frequency,
+        SCAN_STEP_FREQUENCY,
+    );
+    let (fine, _) = scan_tone_frequency(
+        tone,
+        sample_rate,
+        (coarse - SCAN_STEP_FREQUENCY).max(SCAN_MIN_FREQUENCY),
+        (coarse + SCAN_STEP_FREQUENCY).min(max_frequency),
+        SCAN_FINE_STEP_FREQUENCY,
+    );
+    fine
+}
+
+fn tone_timings(samples: &[f32], sample_rate: f64, frequency: f64, window: usize) -> Vec<f64> {
+    let hop = (window / DETECT_HOPS_PER_WINDOW).max(1);
+    let levels: Vec<f64> = (0..=samples.len().saturating_sub(window))
+        .step_by(hop)
+        .map(|start| {
+            let end = (start + window).min(samples.len());
+            goertzel_amplitude(&samples[start..end], frequency, sample_rate)
+        })
+        .collect();
+
+    let mut sorted = levels.clone();
+    sorted.sort_by(f64::total_cmp);
+    let peak = sorted.last().copied().unwrap_or(0.0);
+    if peak < TONE_MIN_AMPLITUDE {
+        return Vec::new();
+    }
+    let floor = sorted[(sorted.len() as f64 * NOISE_FLOOR_PERCENTILE) as usize];
+    let threshold = floor + (peak - floor) * TONE_THRESHOLD;
+
+    let seconds = hop as f64 / sample_rate;
+    let mut timings = Vec::new();
+    let mut state = true;
+    let mut run = 0_usize;
+    for level in levels.iter().skip_while(|level| **level < threshold) {
+        if (*level >= threshold) == state {
+            run += 1;
+            continue;
+        }
+        timings.push(run as f64 * seconds);
+        state = !state;
+        run = 1;
+    }
+    if state {
+        timings.push(run as f64 * seconds);
+    }
+    timings
+}
+
+fn audio_to_timings(samples: &[f32], sample_rate: f64, frequency: Option<f64>) -> Vec<f64> {
+    let frequency = frequency.unwrap_or_else(|| estimate_tone_frequency(samples, sample_rate));
+    let window =
+        ((DETECT_MIN_CYCLES * sample_rate / frequency) as usize).max(DETECT_MIN_WINDOW_SAMPLES);
+    let timings = tone_timings(samples, sample_rate, frequency, window);
+    let (ons, offs) = split_timings(&timings);
+    if ons.is_empty() {
+        return timings;
+    }
+    let bias = timing_bias(&ons, &offs);
+    let ons: Vec<f64> = ons.iter().map(|on| on + bias).collect();
+    let offs: Vec<f64> = offs.iter().map(|off| off - bias).collect();
+    let unit_window = (DETECT_UNIT_FRACTION * estimate_unit(&ons, &offs) * sample_rate) as usize;
+    if unit_window <= window {
+        return timings;
+    }
+    tone_timings(samples, sample_rate, frequency, unit_window)
+}
+
+#[inline(always)]
+fn wav_samples(wav: &[u8]) -> napi::Result<(Vec<f32>, u32)> {
+    let unsupported = || invalid_input("Unsupported WAV data");
+    if wav.len() < 12 || &wav[0..4] != b"RIFF" || &wav[8..12] != b"WAVE" {
+        return Err(unsupported());
+    }
+    let mut offset = 12;
+    let mut format = None;
+    while offset + 8 <= wav.len() {
+        let id = &wav[offset..offset + 4];
+        let size = u32::from_le_bytes(wav[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let end = (offset + 8).saturating_add(size);
+        let body = match wav.get(offset + 8..end) {
+            Some(body) => body,
+            None if id == b"data" => &wav[offset + 8..],
+            None => return Err(unsupported()),
+        };
+        if id == b"fmt " && size >= 16 {
+            let read_u16 = |at: usize| u16::from_le_bytes([body[at], body[at + 1]]);
+            let sample_rate = u32::from_le_bytes(body[4..8].try_into().unwrap());
+            format = Some((
+                read_u16(0),
+                read_u16(2).max(1) as usize,
+                sample_rate,
+                read_u16(14),
+            ));
+        } else if id == b"data" {
+            let (tag, channels, sample_rate, bits) = format.ok_or_else(unsupported)?;
+            let samples = match (tag, bits) {
+                (WAV_FORMAT_PCM, 16) => body
+                    .chunks_exact(2 * channels)
+                    .map(|frame| i16::from_le_bytes([frame[0], frame[1]]) as f32 / i16::MAX as f32)
+                    .collect(),
+                (WAV_FORMAT_FLOAT, 32) => body
+                    .chunks_exact(4 * channels)
+                    .map(|frame| f32::from_le_bytes([frame[0], frame[1], frame[2], frame[3]]))
+                    .collect(),
+                _ => return Err(invalid_input("WAV data must be 16-bit PCM or 32-bit float")),
+            };
+            return Ok((samples, sample_rate));
+        }
+        offset += 8 + size + size % 2;
+    }
+    Err(unsupported())
+}
+
+#[napi(namespace = "morse", js_name = "decodeTimings")]
+pub fn morse_decode_timings(
+    timings: Vec<f64>,
+    options: Option<MorseOptions>,
+) -> napi::Result<String> {
+    let codec = morse_codec(options)?;
+    let code = timings_to_code(&timings)?;
+    morse_decode_with(&code, &codec)
+}
+
+#[napi(namespace = "morse", js_name = "decodeAudio")]
+pub fn morse_decode_audio(
+    input: Either<Float32Array, Buffer>,
+    sample_rate: Option<u32>,
+    options: Option<MorseAudioDecodeOptions>,
+) -> napi::Result<String> {
+    let options = options.unwrap_or_default();
+    let codec = morse_codec(Some(MorseOptions {
+        table: options.table,
+        strict: options.strict,
+        prosigns: options.prosigns,
+    }))?;
+    let (samples, sample_rate) = match input {
+        Either::A(pcm) => {
+            let sample_rate =
+                sample_rate.ok_or_else(|| invalid_input("sampleRate is required for PCM input"))?;
+            (pcm.to_vec(), sample_rate)
+        }
+        Either::B(wav) => {
+            let (samples, header_rate) = wav_samples(&wav)?;
+            (samples, sample_rate.unwrap_or(header_rate))
+        }
+    };
+    if !(MIN_AUDIO_SAMPLE_RATE..=MAX_AUDIO_SAMPLE_RATE).contains(&sample_rate) {
+        return Err(invalid_input("sampleRate must be between 8000 and 192000"));
+    }
+    if let Some(frequency) = options.frequency
+        && !(frequency > 0.0 && frequency < sample_rate as f64 / 2.0)
+    {
+        return Err(invalid_input(
+            "frequency must be greater than 0 and below half the sampleRate",
+        ));
+    }
+
+    let timings = audio_to_timings(&samples, sample_rate as f64, options.frequency);
+    let code = timings_to_code(&timings)?;
+    morse_decode_with(&code, &codec)
+}
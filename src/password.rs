@@ -0,0 +1,331 @@
+#![allow(dead_code)]
+use argon2::Argon2;
+use napi::bindgen_prelude::AsyncTask;
+use napi::{Env, Task};
+use napi_derive::napi;
+use password_hash::rand_core::OsRng;
+use password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use pbkdf2::Pbkdf2;
+use scrypt::Scrypt;
+
+const DEFAULT_ALGORITHM: &str = "argon2id";
+const DEFAULT_ARGON2_T_COST: u32 = 2;
+const DEFAULT_SCRYPT_LOG_N: u32 = 17;
+const MIN_SCRYPT_LOG_N: u32 = 10;
+const MAX_SCRYPT_LOG_N: u32 = 20;
+const DEFAULT_BCRYPT_COST: u32 = 12;
+const MIN_BCRYPT_COST: u32 = 4;
+const MAX_BCRYPT_COST: u32 = 31;
+const DEFAULT_PBKDF2_ROUNDS: u32 = 600_000;
+const PBKDF2_OUTPUT_BYTES: usize = 32;
+const BCRYPT_PREFIXES: [&str; 3] = ["$2a$", "$2b$", "$2y$"];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PasswordAlgorithm {
+    Argon2id,
+    Scrypt,
+    Bcrypt,
+    Pbkdf2,
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct PasswordOptions {
+    pub algorithm: Option<String>,
+    pub cost: Option<u32>,
+}
+
+pub struct PasswordHashTask {
+    password: String,
+    algorithm: PasswordAlgorithm,
+    cost: u32,
+}
+
+pub struct PasswordVerifyTask {
+    password: String,
+    hash: String,
+}
+
+#[inline(always)]
+fn invalid_input(message: &str) -> napi::Error {
+    napi::Error::from_reason(message.to_string())
+}
+
+#[inline(always)]
+fn normalize_password_algorithm(value: Option<String>) -> napi::Result<PasswordAlgorithm> {
+    let value = value.unwrap_or_else(|| DEFAULT_ALGORITHM.to_string());
+    match value.to_ascii_lowercase().as_str() {
+        "argon2id" | "argon2" => Ok(PasswordAlgorithm::Argon2id),
+        "scrypt" => Ok(PasswordAlgorithm::Scrypt),
+        "bcrypt" => Ok(PasswordAlgorithm::Bcrypt),
+        "pbkdf2" => Ok(PasswordAlgorithm::Pbkdf2),
+        _ => Err(invalid_input(
+            "Password algorithm must be one of: argon2id, scrypt, bcrypt, pbkdf2",
+        )),
+    }
+}
+
+#[inline(always)]
+fn default_cost(algorithm: PasswordAlgorithm) -> u32 {
+    match algorithm {
+        PasswordAlgorithm::Argon2id => DEFAULT_ARGON2_T_COST,
+        PasswordAlgorithm::Scrypt => DEFAULT_SCRYPT_LOG_N,
+        PasswordAlgorithm::Bcrypt => DEFAULT_BCRYPT_COST,
+        PasswordAlgorithm::Pbkdf2 => DEFAULT_PBKDF2_ROUNDS,
+    }
+}
+
+#[inline(always)]
+fn normalize_cost(algorithm: PasswordAlgorithm, cost: Option<u32>) -> napi::Result<u32> {
+    let value = cost.unwrap_or_else(|| default_cost(algorithm));
+    match algorithm {
+        PasswordAlgorithm::Argon2id if value == 0 => {
+            Err(invalid_input("Argon2id cost must be greater than 0"))
+        }
+        PasswordAlgorithm::Scrypt if !(MIN_SCRYPT_LOG_N..=MAX_SCRYPT_LOG_N).contains(&value) => {
+            Err(invalid_input("scrypt cost must be in range 10..=20"))
+        }
+        PasswordAlgorithm::Bcrypt if !(MIN_BCRYPT_COST..=MAX_BCRYPT_COST).contains(&value) => {
+            Err(invalid_input("bcrypt cost must be in range 4..=31"))
+        }
+        PasswordAlgorithm::Pbkdf2 if value == 0 => {
+            Err(invalid_input("PBKDF2 cost must be greater than 0"))
+        }
+        _ => Ok(value),
+    }
+}
+
+#[inline(always)]
+fn normalize_password_options(
+    options: Option<PasswordOptions>,
+) -> napi::Result<(PasswordAlgorithm, u32)> {
+    let options = options.unwrap_or_default();
+    let algorithm = normalize_password_algorithm(options.algorithm)?;
+    let cost = normalize_cost(algorithm, options.cost)?;
+    Ok((algorithm, cost))
+}
+
+#[inline(always)]
+fn hash_error(error: impl std::fmt::Display) -> napi::Error {
+    invalid_input(&format!("Password hashing failed: {error}"))
+}
+
+#[inline(always)]
+fn argon2_with_cost(cost: u32) -> napi::Result<Argon2<'static>> {
+    let params = argon2::Params::new(
+        argon2::Params::DEFAULT_M_COST,
+        cost,
+        argon2::Params::DEFAULT_P_COST,
+        None,
+    )
+    .map_err(hash_error)?;
+    Ok(Argon2::new(
+        argon2::Algorithm::Argon2id,
+        argon2::Version::V0x13,
+        params,
+    ))
+}
+
+#[inline(always)]
+fn hash_argon2id(password: &[u8], cost: u32) -> napi::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = argon2_with_cost(cost)?
+        .hash_password(password, &salt)
+        .map_err(hash_error)?;
+    Ok(hash.to_string())
+}
+
+#[inline(always)]
+fn hash_scrypt(password: &[u8], cost: u32) -> napi::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let params = scrypt::Params::new(
+        cost as u8,
+        scrypt::Params::RECOMMENDED_R,
+        scrypt::Params::RECOMMENDED_P,
+        scrypt::Params::RECOMMENDED_LEN,
+    )
+    .map_err(hash_error)?;
+    let hash = Scrypt
+        .hash_password_customized(password, None, None, params, &salt)
+        .map_err(hash_error)?;
+    Ok(hash.to_string())
+}
+
+#[inline(always)]
+fn hash_pbkdf2(password: &[u8], cost: u32) -> napi::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let params = pbkdf2::Params {
+        rounds: cost,
+        output_length: PBKDF2_OUTPUT_BYTES,
+    };
+    let hash = Pbkdf2
+        .hash_password_customized(
+            password,
+            Some(pbkdf2::Algorithm::Pbkdf2Sha256.ident()),
+            None,
+            params,
+            &salt,
+        )
+        .map_err(hash_error)?;
+    Ok(hash.to_string())
+}
+
+#[inline(always)]
+fn hash_bcrypt(password: &[u8], cost: u32) -> napi::Result<String> {
+    bcrypt::non_truncating_hash(password, cost).map_err(hash_error)
+}
+
+#[inline(always)]
+fn hash_password(password: &[u8], algorithm: PasswordAlgorithm, cost: u32) -> napi::Result<String> {
+    match algorithm {
+        PasswordAlgorithm::Argon2id => hash_argon2id(password, cost),
+        PasswordAlgorithm::Scrypt => hash_scrypt(password, cost),
+        PasswordAlgorithm::Bcrypt => hash_bcrypt(password, cost),
+        PasswordAlgorithm::Pbkdf2 => hash_pbkdf2(password, cost),
+    }
+}
+
+#[inline(always)]
+fn is_bcrypt_hash(hash: &str) -> bool {
+    BCRYPT_PREFIXES
+        .iter()
+        .any(|prefix| hash.starts_with(prefix))
+}
+
+#[inline(always)]
+fn detect_algorithm(hash: &str) -> Option<PasswordAlgorithm> {
+    if is_bcrypt_hash(hash) {
+        return Some(PasswordAlgorithm::Bcrypt);
+    }
+    let parsed = PasswordHash::new(hash).ok()?;
+    match parsed.algorithm.as_str() {
+        "argon2id" => Some(PasswordAlgorithm::Argon2id),
+        "scrypt" => Some(PasswordAlgorithm::Scrypt),
+        "pbkdf2" | "pbkdf2-sha256" | "pbkdf2-sha512" => Some(PasswordAlgorithm::Pbkdf2),
+        _ => None,
+    }
+}
+
+#[inline(always)]
+fn verify_phc(password: &[u8], hash: &str, algorithm: PasswordAlgorithm) -> napi::Result<bool> {
+    let parsed = PasswordHash::new(hash)
+        .map_err(|error| invalid_input(&format!("Password hash is invalid: {error}")))?;
+    let result = match algorithm {
+        PasswordAlgorithm::Argon2id => Argon2::default().verify_password(password, &parsed),
+        PasswordAlgorithm::Scrypt => Scrypt.verify_password(password, &parsed),
+        PasswordAlgorithm::Pbkdf2 => Pbkdf2.verify_password(password, &parsed),
+        PasswordAlgorithm::Bcrypt => return Err(invalid_input("Password hash is invalid")),
+    };
+    match result {
+        Ok(()) => Ok(true),
+        Err(password_hash::Error::Password) => Ok(false),
+        Err(error) => Err(invalid_input(&format!("Password hash is invalid: {error}"))),
+    }
+}
+
+#[inline(always)]
+fn verify_password(password: &[u8], hash: &str) -> napi::Result<bool> {
+    let Some(algorithm) = detect_algorithm(hash) else {
+        return Err(invalid_input("Unsupported password hash format"));
+    };
+    if algorithm == PasswordAlgorithm::Bcrypt {
+        return bcrypt::verify(password, hash)
+            .map_err(|error| invalid_input(&format!("Password hash is invalid: {error}")));
+    }
+    verify_phc(password, hash, algorithm)
+}
+
+#[inline(always)]
+fn bcrypt_cost(hash: &str) -> Option<u32> {
+    hash.get(4..6)?.parse().ok()
+}
+
+#[inline(always)]
+fn phc_decimal(hash: &PasswordHash, name: &str) -> Option<u32> {
+    hash.params.get_decimal(name)
+}
+
+#[inline(always)]
+fn phc_matches_cost(hash: &str, algorithm: PasswordAlgorithm, cost: u32) -> bool {
+    let Ok(parsed) = PasswordHash::new(hash) else {
+        return false;
+    };
+    match algorithm {
+        PasswordAlgorithm::Argon2id => {
+            phc_decimal(&parsed, "t") == Some(cost)
+                && phc_decimal(&parsed, "m") == Some(argon2::Params::DEFAULT_M_COST)
+                && phc_decimal(&parsed, "p") == Some(argon2::Params::DEFAULT_P_COST)
+                && parsed.version == Some(argon2::Version::V0x13 as u32)
+        }
+        PasswordAlgorithm::Scrypt => {
+            phc_decimal(&parsed, "ln") == Some(cost)
+                && phc_decimal(&parsed, "r") == Some(scrypt::Params::RECOMMENDED_R)
+                && phc_decimal(&parsed, "p") == Some(scrypt::Params::RECOMMENDED_P)
+        }
+        PasswordAlgorithm::Pbkdf2 => {
+            parsed.algorithm.as_str() == "pbkdf2-sha256" && phc_decimal(&parsed, "i") == Some(cost)
+        }
+        PasswordAlgorithm::Bcrypt => false,
+    }
+}
+
+#[inline(always)]
+fn hash_needs_rehash(hash: &str, algorithm: PasswordAlgorithm, cost: u32) -> bool {
+    if detect_algorithm(hash) != Some(algorithm) {
+        return true;
+    }
+    if algorithm == PasswordAlgorithm::Bcrypt {
+        return bcrypt_cost(hash) != Some(cost);
+    }
+    !phc_matches_cost(hash, algorithm, cost)
+}
+
+impl Task for PasswordHashTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        hash_password(self.password.as_bytes(), self.algorithm, self.cost)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+impl Task for PasswordVerifyTask {
+    type Output = bool;
+    type JsValue = bool;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        verify_password(self.password.as_bytes(), &self.hash)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+#[napi(namespace = "password")]
+pub fn hash(
+    password: String,
+    options: Option<PasswordOptions>,
+) -> napi::Result<AsyncTask<PasswordHashTask>> {
+    let (algorithm, cost) = normalize_password_options(options)?;
+    Ok(AsyncTask::new(PasswordHashTask {
+        password,
+        algorithm,
+        cost,
+    }))
+}
+
+#[napi(namespace = "password")]
+pub fn verify(password: String, hash: String) -> AsyncTask<PasswordVerifyTask> {
+    AsyncTask::new(PasswordVerifyTask { password, hash })
+}
+
+#[napi(namespace = "password", js_name = "needsRehash")]
+pub fn needs_rehash(hash: String, options: Option<PasswordOptions>) -> napi::Result<bool> {
+    let (algorithm, cost) = normalize_password_options(options)?;
+    Ok(hash_needs_rehash(&hash, algorithm, cost))
+}
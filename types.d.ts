@@ -1,18 +1,100 @@
-export type BinaryEncoding = "hex" | "base64"
-export type AesEncoding = "base64" | "hex"
+export type TextEncoding = "hex" | "base16" | "base64" | "base64url" | "base64-nopad" | "base32"
+export type BinaryEncoding = TextEncoding | "buffer"
+export type AesEncoding = BinaryEncoding
+export type Encoded<E extends BinaryEncoding> = E extends "buffer" ? Buffer : string
+export type ByteInput = string | Buffer
+export type HashAlgorithm =
+    | "md5"
+    | "sha1"
+    | "sha224"
+    | "sha256"
+    | "sha384"
+    | "sha512"
+    | "sha512_256"
+    | "sha3_256"
+    | "sha3_512"
+    | "keccak256"
+export type WideOutput = "bigint" | "hex"
+export type PasswordAlgorithm = "argon2id" | "scrypt" | "bcrypt" | "pbkdf2"
 export type PathInput = string | string[]
 export type ExistsEntry = "file" | "folder" | false
 export type ReadEntry = string | string[] | false
 export type ExportKey = "rand" | "crypto" | "file"
 
 export interface RsaPacket {
-    encoded: string
+    encoded: string | Buffer
     privateKey: string
     publicKey: string
     encoding: BinaryEncoding
     bits: number
 }
 
+export interface Blake2Options<E extends BinaryEncoding = BinaryEncoding> {
+    length?: number
+    key?: ByteInput
+    encoding?: E
+}
+
+export interface Blake3Options<E extends BinaryEncoding = BinaryEncoding> {
+    length?: number
+    key?: ByteInput
+    context?: string
+    encoding?: E
+}
+
+export declare class Hmac {
+    constructor(algorithm: HashAlgorithm, key: ByteInput)
+    update(input: ByteInput): void
+    digest<E extends BinaryEncoding = "hex">(encoding?: E): Encoded<E>
+}
+
+export interface PasswordOptions {
+    algorithm?: PasswordAlgorithm
+    cost?: number
+}
+
+export interface PasswordNamespace {
+    hash(password: string, options?: PasswordOptions): Promise<string>
+    verify(password: string, hash: string): Promise<boolean>
+    needsRehash(hash: string, options?: PasswordOptions): boolean
+}
+
+export interface MerkleProof {
+    index: number
+    size: number
+    hash: HashAlgorithm
+    encoding: BinaryEncoding
+    siblings: Array<string | Buffer>
+}
+
+export interface MerkleNamespace {
+    root<E extends BinaryEncoding = "hex">(
+        leaves: readonly ByteInput[],
+        hash?: HashAlgorithm,
+        encoding?: E,
+    ): Encoded<E>
+    proof(
+        leaves: readonly ByteInput[],
+        index: number,
+        hash?: HashAlgorithm,
+        encoding?: BinaryEncoding,
+    ): MerkleProof
+    verify(root: ByteInput, leaf: ByteInput, proof: MerkleProof): boolean
+}
+
+export interface ManifestReport {
+    ok: boolean
+    algorithm: HashAlgorithm
+    missing: string[]
+    modified: string[]
+    extra: string[]
+}
+
+export interface ManifestNamespace {
+    create(dir: string, algorithm?: HashAlgorithm): string
+    verify(manifestPath: string, algorithm?: HashAlgorithm): ManifestReport
+}
+
 export interface RandNamespace {
     int(min?: number, max?: number): number
     float(min?: number, max?: number): number
@@ -26,37 +108,440 @@ export interface RandNamespace {
     ): unknown | null
 }
 
+export interface Base64Options {
+    variant?: "standard" | "url" | "mime"
+    padding?: boolean
+    mode?: "strict" | "lenient"
+}
+
+export interface Base64Namespace {
+    encode(input: ByteInput, options?: Base64Options): string
+    decode(input: ByteInput, options?: Base64Options): string
+    decodeBytes(input: ByteInput, options?: Base64Options): Buffer
+}
+
+export interface Base32Options {
+    variant?: "rfc4648" | "hex" | "crockford" | "zbase32"
+    padding?: boolean
+    mode?: "strict" | "lenient"
+}
+
+export interface Base32Namespace {
+    encode(input: ByteInput, options?: Base32Options): string
+    decode(input: ByteInput, options?: Base32Options): string
+    decodeBytes(input: ByteInput, options?: Base32Options): Buffer
+}
+
 export interface BaseCodecNamespace {
-    encode(input: string): string
+    encode(input: ByteInput): string
+    decode(input: ByteInput): string
+    decodeBytes(input: ByteInput): Buffer
+}
+
+export interface Base58Options {
+    alphabet?: "bitcoin" | "flickr"
+}
+
+export interface Base58Namespace {
+    encode(input: ByteInput, options?: Base58Options): string
+    decode(input: ByteInput, options?: Base58Options): string
+    decodeBytes(input: ByteInput, options?: Base58Options): Buffer
+}
+
+export interface Base85Options {
+    variant?: "ascii85" | "z85" | "rfc1924"
+}
+
+export interface Base85Namespace {
+    encode(input: ByteInput, options?: Base85Options): string
+    decode(input: ByteInput, options?: Base85Options): string
+    decodeBytes(input: ByteInput, options?: Base85Options): Buffer
+}
+
+export type BaseNAlphabet = "base36" | "base62" | (string & {})
+
+export interface BaseNNamespace {
+    encode(input: ByteInput | bigint, alphabet: BaseNAlphabet): string
+    decode(input: ByteInput, alphabet: BaseNAlphabet): string
+    decodeBytes(input: ByteInput, alphabet: BaseNAlphabet): Buffer
+    decodeBigInt(input: ByteInput, alphabet: BaseNAlphabet): bigint
+}
+
+export interface Bech32EncodeOptions {
+    version?: number
+}
+
+export interface Bech32DecodeOptions {
+    witness?: boolean
+}
+
+export interface Bech32Decoded {
+    hrp: string
+    version?: number
+    data: Buffer
+}
+
+export interface Bech32Namespace {
+    encode(hrp: string, data: ByteInput, options?: Bech32EncodeOptions): string
+    decode(input: string, options?: Bech32DecodeOptions): Bech32Decoded
+}
+
+export interface PercentOptions {
+    mode?: "component" | "path"
+}
+
+export interface PercentNamespace {
+    encode(input: ByteInput, options?: PercentOptions): string
+    decode(input: ByteInput): string
+    decodeBytes(input: ByteInput): Buffer
+}
+
+export interface HtmlOptions {
+    mode?: "named" | "numeric"
+    ascii?: boolean
+}
+
+export interface HtmlNamespace {
+    encode(input: string, options?: HtmlOptions): string
+    decode(input: string): string
+}
+
+export interface QuotedPrintableOptions {
+    binary?: boolean
+}
+
+export interface QuotedPrintableNamespace {
+    encode(input: ByteInput, options?: QuotedPrintableOptions): string
+    decode(input: ByteInput): string
+    decodeBytes(input: ByteInput): Buffer
+}
+
+export interface EncodedWordOptions {
+    encoding?: "B" | "Q"
+}
+
+export interface EncodedWordNamespace {
+    encode(input: string, options?: EncodedWordOptions): string
     decode(input: string): string
 }
 
+export interface DetectedEncoding {
+    encoding: string
+    score: number
+    decoded?: string
+}
+
+export interface UnwrapOptions {
+    maxDepth?: number
+}
+
+export interface UnwrapResult {
+    output: string
+    layers: string[]
+}
+
 export interface AesNamespace {
-    encode(input: string, key: string, encoding?: AesEncoding): [encoded: string, ivHex: string]
-    decode(encoded: string, ivHex: string, key: string, encoding?: AesEncoding): string
+    encode<E extends AesEncoding = "base64">(
+        input: string,
+        key: string,
+        encoding?: E,
+    ): [encoded: Encoded<E>, ivHex: string]
+    decode(encoded: ByteInput, ivHex: string, key: string, encoding?: AesEncoding): string
+    encodeAsync<E extends AesEncoding = "base64">(
+        input: ByteInput,
+        key: string,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<[encoded: Encoded<E>, ivHex: string]>
+    decodeAsync(
+        encoded: ByteInput,
+        ivHex: string,
+        key: string,
+        encoding?: AesEncoding,
+        signal?: AbortSignal,
+    ): Promise<string>
 }
 
 export interface RsaNamespace {
     encode(input: string, encoding?: BinaryEncoding, bits?: number): RsaPacket
     decode(packet: RsaPacket): string
+    encodeAsync(
+        input: ByteInput,
+        encoding?: BinaryEncoding,
+        bits?: number,
+        signal?: AbortSignal,
+    ): Promise<RsaPacket>
+    decodeAsync(packet: RsaPacket, signal?: AbortSignal): Promise<string>
+}
+
+export type MorseTable = "itu" | "latin" | "cyrillic" | "greek" | "wabun" | Record<string, string>
+
+export interface MorseOptions {
+    table?: MorseTable
+    strict?: boolean
+    prosigns?: boolean
+}
+
+export interface MorseAudioOptions {
+    wpm?: number
+    farnsworth?: number
+    frequency?: number
+    sampleRate?: number
+    volume?: number
+    format?: "wav" | "pcm"
+    path?: string
+    table?: MorseTable
+    strict?: boolean
+}
+
+export interface MorseAudioDecodeOptions extends MorseOptions {
+    frequency?: number
 }
 
 export interface MorseNamespace {
-    encode(input: string): string
-    decode(input: string): string
+    encode(input: string, options?: MorseOptions): string
+    decode(input: string, options?: MorseOptions): string
+    toAudio(input: string, options?: MorseAudioOptions & { format?: "wav" }): Buffer
+    toAudio(input: string, options: MorseAudioOptions & { format: "pcm" }): Float32Array
+    decodeTimings(timings: number[], options?: MorseOptions): string
+    decodeAudio(input: Float32Array | Buffer, sampleRate?: number, options?: MorseAudioDecodeOptions): string
+}
+
+export interface KeyedCipher<K extends unknown[] = [key: string]> {
+    encrypt(input: string, ...key: K): string
+    decrypt(input: string, ...key: K): string
+}
+
+export interface BaconOptions {
+    alphabet?: "classic" | "full"
+}
+
+export interface ClassicNamespace {
+    vigenere: KeyedCipher
+    beaufort: KeyedCipher
+    autokey: KeyedCipher
+    atbash: KeyedCipher<[]>
+    affine: KeyedCipher<[a: number, b: number]>
+    substitution: KeyedCipher
+    playfair: KeyedCipher
+    railFence: KeyedCipher<[rails: number, offset?: number]>
+    columnar: KeyedCipher
+    bacon: KeyedCipher<[options?: BaconOptions]>
+}
+
+export interface RotCandidate {
+    shift: number
+    text: string
+    score: number
+}
+
+export interface VigenereCrack {
+    key: string
+    keyLength: number
+    plaintext: string
+    score: number
+}
+
+export interface CrackNamespace {
+    rot(ciphertext: string): RotCandidate[]
+    vigenere(ciphertext: string, options?: { maxKeyLength?: number }): VigenereCrack
+    vigenereAsync(
+        ciphertext: string,
+        options?: { maxKeyLength?: number },
+        signal?: AbortSignal,
+    ): Promise<VigenereCrack>
+}
+
+export interface LetterFrequency {
+    letter: string
+    count: number
+    frequency: number
+}
+
+export interface FrequencyReport {
+    total: number
+    letters: LetterFrequency[]
+    indexOfCoincidence: number
+    chiSquared: number
+}
+
+export interface AnalysisNamespace {
+    frequency(text: string): FrequencyReport
+}
+
+export interface RotOptions {
+    alphabet?: "rot13" | "rot5" | "rot18" | "rot47" | (string & {})
 }
 
 export interface CryptoNamespace {
-    base64: BaseCodecNamespace
+    base64: Base64Namespace
     base16: BaseCodecNamespace
-    base32: BaseCodecNamespace
-    md5(input: string, encoding?: BinaryEncoding): string
-    sha256(input: string, encoding?: BinaryEncoding): string
-    sha512(input: string, encoding?: BinaryEncoding): string
+    base32: Base32Namespace
+    base58: Base58Namespace
+    base58check: Base58Namespace
+    base85: Base85Namespace
+    baseN: BaseNNamespace
+    bech32: Bech32Namespace
+    bech32m: Bech32Namespace
+    percent: PercentNamespace
+    html: HtmlNamespace
+    quotedPrintable: QuotedPrintableNamespace
+    encodedWord: EncodedWordNamespace
+    classic: ClassicNamespace
+    crack: CrackNamespace
+    analysis: AnalysisNamespace
+    md5<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha256<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha512<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha1<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha224<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha384<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha512_256<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha3_256<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    sha3_512<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    keccak256<E extends BinaryEncoding = "hex">(input: string, encoding?: E): Encoded<E>
+    md5Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha1Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha224Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha256Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha384Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha512Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha512_256Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha3_256Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    sha3_512Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    keccak256Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        encoding?: E,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    shake128<E extends BinaryEncoding = "hex">(
+        input: string,
+        length?: number,
+        encoding?: E,
+    ): Encoded<E>
+    shake256<E extends BinaryEncoding = "hex">(
+        input: string,
+        length?: number,
+        encoding?: E,
+    ): Encoded<E>
+    blake2b<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        options?: Blake2Options<E>,
+    ): Encoded<E>
+    blake2s<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        options?: Blake2Options<E>,
+    ): Encoded<E>
+    blake3<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        options?: Blake3Options<E>,
+    ): Encoded<E>
+    blake3Async<E extends BinaryEncoding = "hex">(
+        input: ByteInput,
+        options?: Blake3Options<E>,
+        signal?: AbortSignal,
+    ): Promise<Encoded<E>>
+    hmac<E extends BinaryEncoding = "hex">(
+        algorithm: HashAlgorithm,
+        key: ByteInput,
+        message: ByteInput,
+        encoding?: E,
+    ): Encoded<E>
+    hmacVerify(
+        algorithm: HashAlgorithm,
+        key: ByteInput,
+        message: ByteInput,
+        signature: ByteInput,
+        encoding?: BinaryEncoding,
+    ): boolean
+    Hmac: typeof Hmac
+    timingSafeEqual(left: ByteInput, right: ByteInput): boolean
+    verifyDigest(
+        input: ByteInput,
+        expected: ByteInput,
+        algorithm?: HashAlgorithm,
+        encoding?: BinaryEncoding,
+    ): boolean
+    hkdf(
+        ikm: ByteInput,
+        salt: ByteInput,
+        info: ByteInput,
+        length: number,
+        hash?: HashAlgorithm,
+    ): Buffer
+    pbkdf2(
+        password: ByteInput,
+        salt: ByteInput,
+        iterations: number,
+        length: number,
+        hash?: HashAlgorithm,
+    ): Buffer
+    pbkdf2Async(
+        password: ByteInput,
+        salt: ByteInput,
+        iterations: number,
+        length: number,
+        hash?: HashAlgorithm,
+        signal?: AbortSignal,
+    ): Promise<Buffer>
+    xxh3(input: ByteInput, seed?: number | bigint, output?: "bigint"): bigint
+    xxh3(input: ByteInput, seed: number | bigint | undefined, output: "hex"): string
+    xxh64(input: ByteInput, seed?: number | bigint, output?: "bigint"): bigint
+    xxh64(input: ByteInput, seed: number | bigint | undefined, output: "hex"): string
+    murmur3_32(input: ByteInput, seed?: number): number
+    murmur3_128(input: ByteInput, seed?: number, output?: "bigint"): bigint
+    murmur3_128(input: ByteInput, seed: number | undefined, output: "hex"): string
+    fnv1a(input: ByteInput, seed?: number): number
+    fnv1a64(input: ByteInput, seed?: number | bigint, output?: "bigint"): bigint
+    fnv1a64(input: ByteInput, seed: number | bigint | undefined, output: "hex"): string
+    crc32(input: ByteInput, seed?: number): number
+    crc32c(input: ByteInput, seed?: number): number
+    adler32(input: ByteInput, seed?: number): number
     AES: AesNamespace
     RSA: RsaNamespace
     morse: MorseNamespace
-    rot(input: string, shift?: number): string
+    password: PasswordNamespace
+    merkle: MerkleNamespace
+    manifest: ManifestNamespace
+    rot(input: string, shift?: number, options?: RotOptions): string
+    detect(input: string): DetectedEncoding[]
+    unwrap(input: string, options?: UnwrapOptions): UnwrapResult
 }
 
 export interface FileNamespace {